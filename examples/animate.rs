@@ -0,0 +1,66 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{AnimationFormat, FrameNumber, FrameRenderer, ObjectData, Sprites};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 4 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let path = game_dir.join(&args[1]);
+    let frame_number = FrameNumber(args[2].parse()?);
+    let out_path = Path::new(&args[3]);
+    let overlay = args[4..].iter().any(|arg| arg == "--overlay");
+    let format = out_path
+        .extension()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap_or_default()
+        .parse::<AnimationFormat>()?;
+
+    let contents = ObjectData::open(&path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+    let sprites = Sprites::load(game_dir, &object_data.header)?;
+    let frame_renderer = FrameRenderer::new(&sprites, overlay);
+
+    let animation = frame_renderer
+        .animation(&object_data.frames, frame_number)
+        .ok_or_else(|| format!("Frame `{}` does not exist.", frame_number))?;
+    animation.write(out_path, format)?;
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <object_file> <frame_number> <out_file> [--overlay]`\n\
+        \n\
+        Writes the chain of `next` frames as an animated GIF or PNG, chosen by\n\
+        the `<out_file>` extension.\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 data/freeze.dat 235 target/freeze_235.gif\n\
+        ./{app} path/to/lf2 data/freeze.dat 235 target/freeze_235.png --overlay\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,75 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{BalanceReport, DataTxt, GameData, GameObject, ObjectData, Sprites};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.is_empty() {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let data_txt_contents =
+        ObjectData::open(&game_dir.join("data").join("data.txt")).map_err(|e| e.to_string())?;
+    let data_txt = DataTxt::try_from(data_txt_contents.as_ref()).map_err(|e| e.to_string())?;
+
+    let mut game_data = GameData::new();
+    data_txt.objects.iter().for_each(|entry| {
+        let path = Sprites::path_resolve(game_dir, &entry.file);
+        let object_data = ObjectData::open(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())
+            });
+
+        match object_data {
+            Ok(object_data) => {
+                game_data.insert(GameObject {
+                    id: entry.id,
+                    object_type: entry.object_type,
+                    object_data,
+                });
+            }
+            Err(e) => eprintln!("Skipping `{}`: {}", path.display(), e),
+        }
+    });
+
+    let balance_report = BalanceReport::new(&game_data);
+    if args.iter().any(|arg| arg == "--csv") {
+        print!("{}", balance_report.to_csv());
+    } else {
+        print!("{}", balance_report);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> [--csv]`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2\n\
+        ./{app} path/to/lf2 --csv > balance.csv\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,68 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::ObjectData;
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.is_empty() {
+        print_help();
+        return Ok(());
+    }
+
+    let path = Path::new(&args[0]);
+    let contents = ObjectData::open(path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+
+    let frames = &object_data.frames;
+    print!("{}", frames.commands());
+
+    frames
+        .commands()
+        .standing_conflicts(frames)
+        .iter()
+        .for_each(|command_transitions| {
+            eprintln!(
+                "\nwarning: `{}` leads to different moves from different standing frames:",
+                command_transitions.command
+            );
+            command_transitions
+                .transitions
+                .iter()
+                .for_each(|transition| {
+                    eprintln!(
+                        "  frame {} -> {}",
+                        transition.frame_number, transition.frame_number_next
+                    )
+                });
+        });
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <object_file>`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} examples/frozen.txt\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,53 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{FrameRenderer, ObjectData, Sprites};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 3 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let path = game_dir.join(&args[1]);
+    let out_path = Path::new(&args[2]);
+    let contents = ObjectData::open(&path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+    let sprites = Sprites::load(game_dir, &object_data.header)?;
+    let frame_renderer = FrameRenderer::new(&sprites, true);
+
+    frame_renderer.contact_sheet_png(&object_data.frames, out_path)?;
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <object_file> <out_file>`\n\
+        \n\
+        Writes every frame with hitbox overlays to one PNG, grouped by name.\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 data/freeze.dat target/freeze.png\n\
+        ```
+        ",
+        app = app
+    );
+}
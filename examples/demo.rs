@@ -14,7 +14,7 @@ fn run() -> Result<(), Error<'static>> {
 
     args_os.try_for_each(|arg_os| {
         let path = Path::new(&arg_os);
-        let contents = ObjectData::open(&path)?;
+        let contents = ObjectData::open(path)?;
 
         match ObjectData::try_from(contents.as_ref()) {
             Ok(object_data) => println!("{:#?}", object_data),
@@ -0,0 +1,55 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{ObjectData, ObjectDataDiff};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 2 {
+        print_help();
+        return Ok(());
+    }
+
+    let object_data_load = |path: &Path| -> Result<ObjectData, String> {
+        let contents = ObjectData::open(path).map_err(|e| e.to_string())?;
+        ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())
+    };
+    let before = object_data_load(Path::new(&args[0]))?;
+    let after = object_data_load(Path::new(&args[1]))?;
+
+    let object_data_diff = ObjectDataDiff::new(&before, &after);
+    if object_data_diff.is_empty() {
+        println!("No differences.");
+    } else {
+        print!("{}", object_data_diff);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <object_file_before> <object_file_after>`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} examples/frozen.dat examples/frozen.txt\n\
+        ```
+        ",
+        app = app
+    );
+}
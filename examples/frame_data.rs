@@ -0,0 +1,53 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{FrameNumber, ObjectData};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 2 {
+        print_help();
+        return Ok(());
+    }
+
+    let path = Path::new(&args[0]);
+    let contents = ObjectData::open(path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+
+    args[1..].iter().try_for_each(|frame_number| {
+        let frame_number = FrameNumber(frame_number.parse()?);
+        match object_data.frames.timing(frame_number) {
+            Some(frame_timing) => println!("{}", frame_timing),
+            None => eprintln!("Frame `{}` does not exist.", frame_number),
+        }
+
+        Result::<(), Box<dyn std::error::Error>>::Ok(())
+    })
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <object_file> <frame_number>...`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} examples/frozen.txt 60 235\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,130 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{
+    DataTxt, ElementBounds, FrameLimit, FrameLoops, FrameNumber, FrameReachability, GameData,
+    GameObject, ItrFields, ObjectData, ObjectDataParser, ObjectType, ParseOptions, ParseOutput,
+    PicUsage, Sprites, StateRules,
+};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 2 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let path = game_dir.join(&args[1]);
+    let frame_limit = args
+        .get(2)
+        .map(|profile| profile.parse::<FrameLimit>())
+        .transpose()?
+        .unwrap_or(FrameLimit::LF2_V2_0);
+    let contents = ObjectData::open(&path).map_err(|e| e.to_string())?;
+    let ParseOutput {
+        object_data,
+        mut diagnostics,
+        ..
+    } = ObjectDataParser::with_options(ParseOptions::lenient(frame_limit))
+        .object_data_parse(contents.as_ref())
+        .map_err(|e| e.to_string())?;
+    let (object_type, entries_external) = object_entries(game_dir, &path);
+
+    diagnostics.extend(PicUsage::check(&object_data.header, &object_data.frames));
+    diagnostics.extend(ElementBounds::check(
+        &object_data.header,
+        &object_data.frames,
+    ));
+    diagnostics.extend(ItrFields::check(&object_data.frames));
+    diagnostics.extend(FrameLoops::check(object_type, &object_data.frames));
+    diagnostics.extend(StateRules::default().check(object_type, &object_data.frames));
+    diagnostics.extend(
+        FrameReachability::new(object_type, &object_data.frames, &entries_external)
+            .check(&object_data.frames),
+    );
+    match Sprites::load(game_dir, &object_data.header) {
+        Ok(sprites) => diagnostics.extend(PicUsage::check_sprites(&sprites)),
+        Err(e) => eprintln!("Skipping sprite sheet checks. {}", e),
+    }
+
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.severity, diagnostic.frame_number));
+    diagnostics
+        .iter()
+        .for_each(|diagnostic| println!("{}", diagnostic));
+    println!("{} problem(s) found.", diagnostics.len());
+
+    Ok(())
+}
+
+/// Returns the type of the object from `data.txt`, and the frames that other
+/// objects switch it to.
+///
+/// Defaults to a character with no external entry frames when the object is
+/// not listed.
+fn object_entries(game_dir: &Path, path: &Path) -> (ObjectType, Vec<FrameNumber>) {
+    let data_txt_path = game_dir.join("data").join("data.txt");
+    let data_txt = ObjectData::open(&data_txt_path)
+        .ok()
+        .and_then(|contents| DataTxt::try_from(contents.as_ref()).ok());
+    let data_txt = match data_txt {
+        Some(data_txt) => data_txt,
+        None => return (ObjectType::default(), Vec::new()),
+    };
+
+    let mut game_data = GameData::new();
+    data_txt.objects.iter().for_each(|entry| {
+        let object_data = ObjectData::open(&Sprites::path_resolve(game_dir, &entry.file))
+            .ok()
+            .and_then(|contents| ObjectData::try_from(contents.as_ref()).ok());
+        if let Some(object_data) = object_data {
+            game_data.insert(GameObject {
+                id: entry.id,
+                object_type: entry.object_type,
+                object_data,
+            });
+        }
+    });
+
+    data_txt
+        .objects
+        .iter()
+        .find(|entry| Sprites::path_resolve(game_dir, &entry.file) == path)
+        .map(|entry| {
+            (
+                entry.object_type,
+                FrameReachability::entries_external(&game_data, entry.id),
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <object_file> [frame_limit]`\n\
+        \n\
+        `frame_limit` is one of `2.0` (default), `2.0a`, or `extended`.\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 data/freeze.dat\n\
+        ./{app} path/to/lf2 data/freeze.dat extended\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,47 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::ObjectData;
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.is_empty() {
+        print_help();
+        return Ok(());
+    }
+
+    let path = Path::new(&args[0]);
+    let contents = ObjectData::open(path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+
+    print!("{}", object_data.frames.moves());
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <object_file>`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} examples/frozen.txt\n\
+        ```
+        ",
+        app = app
+    );
+}
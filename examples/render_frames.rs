@@ -0,0 +1,67 @@
+use std::{convert::TryFrom, env, path::Path};
+
+use lf2_parse::{FrameNumber, FrameRenderer, ObjectData, Sprites};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() < 3 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let path = game_dir.join(&args[1]);
+    let out_dir = Path::new(&args[2]);
+    let contents = ObjectData::open(&path).map_err(|e| e.to_string())?;
+    let object_data = ObjectData::try_from(contents.as_ref()).map_err(|e| e.to_string())?;
+    let sprites = Sprites::load(game_dir, &object_data.header)?;
+    let frame_renderer = FrameRenderer::new(&sprites, true);
+
+    let frame_numbers = args[3..]
+        .iter()
+        .map(|frame_number| frame_number.parse().map(FrameNumber))
+        .collect::<Result<Vec<FrameNumber>, _>>()?;
+
+    object_data
+        .frames
+        .iter()
+        .filter(|frame| frame_numbers.is_empty() || frame_numbers.contains(&frame.number))
+        .try_for_each(|frame| {
+            let png_path = out_dir.join(format!("{}.png", frame.number));
+            frame_renderer.render_png(frame, &png_path)?;
+            println!("{}", png_path.display());
+
+            Result::<(), Box<dyn std::error::Error>>::Ok(())
+        })
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <object_file> <out_dir> [frame_number...]`\n\
+        \n\
+        Renders frames with hitbox overlays to `<out_dir>/<frame_number>.png`.\n\
+        All frames are rendered when no frame numbers are given.\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 data/freeze.dat target/freeze 0 60\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,85 @@
+use std::{
+    convert::TryFrom,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use lf2_parse::{DataTxt, Error, GameData, GameObject, ObjectData, Replay};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() != 2 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let replay = fs::read_to_string(&args[1])?.parse::<Replay>()?;
+
+    let data_txt_contents =
+        ObjectData::open(&game_dir.join("data").join("data.txt")).map_err(|e| e.to_string())?;
+    let data_txt = DataTxt::try_from(data_txt_contents.as_ref()).map_err(|e| e.to_string())?;
+
+    let mut game_data = GameData::new();
+    data_txt.objects.iter().for_each(|entry| {
+        let path = game_path(game_dir, &entry.file);
+        let object_data = ObjectData::open(&path).and_then(|contents| {
+            ObjectData::try_from(contents.as_ref()).map_err(|e| {
+                eprintln!("Failed to parse `{}`: {}", path.display(), e);
+                Error::ObjectDataExpected
+            })
+        });
+
+        if let Ok(object_data) = object_data {
+            game_data.insert(GameObject {
+                id: entry.id,
+                object_type: entry.object_type,
+                object_data,
+            });
+        }
+    });
+
+    let simulation = replay.play(&game_data)?;
+    println!(
+        "Replay matches the game data for {} ticks.",
+        simulation.tick_count()
+    );
+
+    Ok(())
+}
+
+/// Returns the path to a file referenced by a data file, which uses `\` as the
+/// separator.
+fn game_path(game_dir: &Path, file: &Path) -> PathBuf {
+    file.to_string_lossy()
+        .split('\\')
+        .fold(game_dir.to_path_buf(), |path, segment| path.join(segment))
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <replay_file>`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 bug_report.replay\n\
+        ```
+        ",
+        app = app
+    );
+}
@@ -0,0 +1,105 @@
+use std::{
+    convert::TryFrom,
+    env,
+    path::{Path, PathBuf},
+};
+
+use lf2_parse::{
+    DataTxt, Error, Facing, FrameNumber, GameData, GameObject, ObjectData, ObjectId, Position,
+    Simulation,
+};
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.len() != 4 {
+        print_help();
+        return Ok(());
+    }
+
+    let game_dir = Path::new(&args[0]);
+    let object_id = ObjectId(args[1].parse()?);
+    let frame_number = FrameNumber(args[2].parse()?);
+    let tick_count = args[3].parse::<u64>()?;
+
+    let data_txt_contents =
+        ObjectData::open(&game_dir.join("data").join("data.txt")).map_err(|e| e.to_string())?;
+    let data_txt = DataTxt::try_from(data_txt_contents.as_ref()).map_err(|e| e.to_string())?;
+
+    let mut game_data = GameData::new();
+    data_txt.objects.iter().for_each(|entry| {
+        let path = game_path(game_dir, &entry.file);
+        let object_data = ObjectData::open(&path).and_then(|contents| {
+            ObjectData::try_from(contents.as_ref()).map_err(|e| {
+                eprintln!("Failed to parse `{}`: {}", path.display(), e);
+                Error::ObjectDataExpected
+            })
+        });
+
+        if let Ok(object_data) = object_data {
+            game_data.insert(GameObject {
+                id: entry.id,
+                object_type: entry.object_type,
+                object_data,
+            });
+        }
+    });
+
+    let mut simulation = Simulation::new(&game_data);
+    simulation.spawn(object_id, frame_number, Position::default(), Facing::Right)?;
+
+    (0..tick_count).try_for_each(|_| {
+        simulation.tick()?;
+
+        println!("tick {}", simulation.tick_count());
+        simulation.instances().iter().for_each(|instance| {
+            println!(
+                "  #{} oid: {} frame: {} pos: ({:.1}, {:.1}, {:.1}) facing: {:?}",
+                instance.id,
+                instance.object_id,
+                instance.frame_number,
+                instance.position.x,
+                instance.position.y,
+                instance.position.z,
+                instance.facing,
+            );
+        });
+
+        Result::<(), Box<dyn std::error::Error>>::Ok(())
+    })
+}
+
+/// Returns the path to a file referenced by a data file, which uses `\` as the
+/// separator.
+fn game_path(game_dir: &Path, file: &Path) -> PathBuf {
+    file.to_string_lossy()
+        .split('\\')
+        .fold(game_dir.to_path_buf(), |path, segment| path.join(segment))
+}
+
+fn main() {
+    if let Err(e) = run() {
+        print_help();
+
+        eprintln!("{}", e);
+    }
+}
+
+fn print_help() {
+    let app = Path::new(file!())
+        .file_stem()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap();
+
+    eprintln!(
+        "\
+        Usage: `./{app} <game_dir> <object_id> <frame_number> <ticks>`\n\
+        \n\
+        Examples:\n\
+        \n\
+        ```sh\n\
+        ./{app} path/to/lf2 11 246 20\n\
+        ```
+        ",
+        app = app
+    );
+}
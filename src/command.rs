@@ -0,0 +1,131 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Frame, FrameNumberNext};
+
+pub use self::command_parse_error::CommandParseError;
+
+mod command_parse_error;
+
+/// Input that a frame's `hit_*` tags respond to.
+///
+/// Special move commands are entered as `D` followed by a direction and `A`
+/// or `J`, and are written in the game's notation, e.g. `D>A` for `hit_Fa`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Command {
+    /// `A`, the `hit_a` tag.
+    Attack,
+    /// `D`, the `hit_d` tag.
+    Defend,
+    /// `J`, the `hit_j` tag.
+    Jump,
+    /// `D>A`, the `hit_Fa` tag.
+    ForwardAttack,
+    /// `D^A`, the `hit_Ua` tag.
+    UpAttack,
+    /// `DvA`, the `hit_Da` tag.
+    DownAttack,
+    /// `D>J`, the `hit_Fj` tag.
+    ForwardJump,
+    /// `D^J`, the `hit_Uj` tag.
+    UpJump,
+    /// `DvJ`, the `hit_Dj` tag.
+    DownJump,
+    /// `DJA`, the `hit_ja` tag.
+    JumpAttack,
+}
+
+impl Command {
+    /// All commands, in the order they are usually listed.
+    pub const ALL: [Self; 10] = [
+        Self::Attack,
+        Self::Defend,
+        Self::Jump,
+        Self::ForwardAttack,
+        Self::UpAttack,
+        Self::DownAttack,
+        Self::ForwardJump,
+        Self::UpJump,
+        Self::DownJump,
+        Self::JumpAttack,
+    ];
+
+    /// Returns the frame tag for this command, e.g. `hit_Fa`.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Attack => "hit_a",
+            Self::Defend => "hit_d",
+            Self::Jump => "hit_j",
+            Self::ForwardAttack => "hit_Fa",
+            Self::UpAttack => "hit_Ua",
+            Self::DownAttack => "hit_Da",
+            Self::ForwardJump => "hit_Fj",
+            Self::UpJump => "hit_Uj",
+            Self::DownJump => "hit_Dj",
+            Self::JumpAttack => "hit_ja",
+        }
+    }
+
+    /// Returns the game's notation for this command, e.g. `D>A`.
+    pub fn notation(self) -> &'static str {
+        match self {
+            Self::Attack => "A",
+            Self::Defend => "D",
+            Self::Jump => "J",
+            Self::ForwardAttack => "D>A",
+            Self::UpAttack => "D^A",
+            Self::DownAttack => "DvA",
+            Self::ForwardJump => "D>J",
+            Self::UpJump => "D^J",
+            Self::DownJump => "DvJ",
+            Self::JumpAttack => "DJA",
+        }
+    }
+
+    /// Returns whether this is a special move command, starting with `D`.
+    pub fn is_special(self) -> bool {
+        !matches!(self, Self::Attack | Self::Defend | Self::Jump)
+    }
+
+    /// Returns the frame that a frame switches to on this command.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame`: Frame that the command is entered in.
+    pub fn frame_number_next(self, frame: &Frame) -> FrameNumberNext {
+        match self {
+            Self::Attack => frame.hit_a,
+            Self::Defend => frame.hit_d,
+            Self::Jump => frame.hit_j,
+            Self::ForwardAttack => frame.hit_fa,
+            Self::UpAttack => frame.hit_ua,
+            Self::DownAttack => frame.hit_da,
+            Self::ForwardJump => frame.hit_fj,
+            Self::UpJump => frame.hit_uj,
+            Self::DownJump => frame.hit_dj,
+            Self::JumpAttack => frame.hit_ja,
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.notation())
+    }
+}
+
+impl FromStr for Command {
+    type Err = CommandParseError;
+
+    /// Parses a command from its notation, e.g. `D>A`, or its tag, e.g.
+    /// `hit_Fa`.
+    fn from_str(s: &str) -> Result<Command, CommandParseError> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|command| command.notation() == s || command.tag() == s)
+            .ok_or_else(|| CommandParseError(s.to_string()))
+    }
+}
@@ -0,0 +1,19 @@
+use std::{fmt, fmt::Display};
+
+/// Error when parsing a string as a `Command`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandParseError(pub String);
+
+impl Display for CommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not recognized as a command.\n\
+            Valid values are `A`, `D`, `J`, `D>A`, `D^A`, `DvA`, `D>J`, `D^J`, `DvJ`, `DJA`, \
+            or their `hit_*` tags.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for CommandParseError {}
@@ -0,0 +1,62 @@
+use std::convert::TryFrom;
+
+use pest::{iterators::Pair, Parser};
+
+use crate::{Error, ObjectDataParser, Rule, SubRuleFn};
+
+pub use self::data_txt_entry::DataTxtEntry;
+
+mod data_txt_entry;
+
+/// Index of game objects, as listed in `data\data.txt`.
+///
+/// Only the `<object>` section is parsed; backgrounds and file editing
+/// sections are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataTxt {
+    /// Objects listed in the `<object>` section.
+    pub objects: Vec<DataTxtEntry>,
+}
+
+impl DataTxt {
+    fn parse_objects<'i>(
+        data_txt: DataTxt,
+        objects_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxt, Error<'i>> {
+        if objects_pair.as_rule() == Rule::DataTxtObjects {
+            objects_pair
+                .into_inner()
+                .try_fold(data_txt, |mut data_txt, entry_pair| {
+                    DataTxtEntry::try_from(entry_pair).map(|entry| {
+                        data_txt.objects.push(entry);
+                        data_txt
+                    })
+                })
+        } else {
+            Err(Error::GrammarSingle {
+                rule_expected: Rule::DataTxtObjects,
+                pair_found: Some(objects_pair),
+            })
+        }
+    }
+}
+
+impl<'i> TryFrom<Pair<'i, Rule>> for DataTxt {
+    type Error = Error<'i>;
+
+    fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
+        let sub_rule_fns: &[SubRuleFn<DataTxt>] = &[Self::parse_objects];
+        ObjectDataParser::parse_as_type(DataTxt::default(), pair, Rule::DataTxt, sub_rule_fns)
+    }
+}
+
+impl<'s> TryFrom<&'s str> for DataTxt {
+    type Error = Error<'s>;
+
+    fn try_from(data_txt_str: &'s str) -> Result<Self, Self::Error> {
+        ObjectDataParser::parse(Rule::DataTxt, data_txt_str)?
+            .next()
+            .ok_or(Error::DataTxtExpected)
+            .and_then(DataTxt::try_from)
+    }
+}
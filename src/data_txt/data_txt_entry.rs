@@ -0,0 +1,111 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use pest::iterators::Pair;
+
+use crate::{Error, ObjectDataParser, ObjectId, ObjectType, Rule, SubRuleFn};
+
+/// An object listed in `data.txt`.
+///
+/// ```text
+/// id: 1  type: 0  file: data\deep.dat
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataTxtEntry {
+    /// ID that other objects use to refer to this object, e.g. `opoint: oid:`.
+    pub id: ObjectId,
+    /// Type of the object.
+    pub object_type: ObjectType,
+    /// Path to the object data file, relative to the game directory.
+    pub file: PathBuf,
+}
+
+impl DataTxtEntry {
+    fn parse_id<'i>(
+        entry: DataTxtEntry,
+        id_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        ObjectDataParser::parse_as_type(
+            entry,
+            id_pair,
+            Rule::TagId,
+            &[Self::parse_id_value as SubRuleFn<_>],
+        )
+    }
+
+    fn parse_id_value<'i>(
+        mut entry: DataTxtEntry,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        let id = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(id),
+                value_pair,
+                error,
+            })?;
+        entry.id = id;
+        Ok(entry)
+    }
+
+    fn parse_type<'i>(
+        entry: DataTxtEntry,
+        type_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        ObjectDataParser::parse_as_type(
+            entry,
+            type_pair,
+            Rule::TagType,
+            &[Self::parse_type_value as SubRuleFn<_>],
+        )
+    }
+
+    fn parse_type_value<'i>(
+        mut entry: DataTxtEntry,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        let object_type = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseObjectType { value_pair, error })?;
+        entry.object_type = object_type;
+        Ok(entry)
+    }
+
+    fn parse_file<'i>(
+        entry: DataTxtEntry,
+        file_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        ObjectDataParser::parse_as_type(
+            entry,
+            file_pair,
+            Rule::TagFileValue,
+            &[Self::parse_file_value as SubRuleFn<_>],
+        )
+    }
+
+    fn parse_file_value<'i>(
+        mut entry: DataTxtEntry,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<DataTxtEntry, Error<'i>> {
+        let file = value_pair.as_str().parse().map_err(|_| Error::ParsePath {
+            field: stringify!(file),
+            value_pair,
+        })?;
+        entry.file = file;
+        Ok(entry)
+    }
+}
+
+impl<'i> TryFrom<Pair<'i, Rule>> for DataTxtEntry {
+    type Error = Error<'i>;
+
+    fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
+        ObjectDataParser::parse_as_type(
+            DataTxtEntry::default(),
+            pair,
+            Rule::DataTxtObject,
+            &[Self::parse_id, Self::parse_type, Self::parse_file],
+        )
+    }
+}
@@ -3,9 +3,10 @@ use std::str::FromStr;
 use crate::{BdyKindParseError, FrameNumberNext};
 
 /// Hittable volume of an object.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum BdyKind {
     /// Regular hittable body.
+    #[default]
     Normal,
     /// For type 5, frame number to switch to when hit by [`ItrKind::Normal`].
     Hostage {
@@ -21,12 +22,6 @@ impl BdyKind {
     }
 }
 
-impl Default for BdyKind {
-    fn default() -> Self {
-        BdyKind::Normal
-    }
-}
-
 impl FromStr for BdyKind {
     type Err = BdyKindParseError;
 
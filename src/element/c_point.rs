@@ -81,7 +81,7 @@ pub struct CPoint {
     ///
     /// `1` if they can, `0` if not.
     pub hurtable: bool,
-    ///
+    /// Health points the caught character loses when the catcher attacks.
     pub injury: i32,
     /// Frame number to switch to when the `Attack` button is pressed.
     pub a_action: FrameNumberNext,
@@ -146,6 +146,13 @@ pub struct CPoint {
 }
 
 impl CPoint {
+    /// Placeholder value (`0xCDCDCDCD`) that appears in some `throwvx`,
+    /// `throwvy`, `throwvz` and `throwinjury` fields.
+    ///
+    /// This is uninitialized memory written out by the original data, and is
+    /// treated as if the field were not set.
+    pub const VALUE_UNSET: i32 = -842_150_451;
+
     fn parse_tags<'i>(
         c_point: CPoint,
         c_point_data_pair: Pair<'i, Rule>,
@@ -3,20 +3,15 @@ use std::str::FromStr;
 use crate::CPointKindParseError;
 
 /// Variants of `CPoint`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum CPointKind {
     /// The object that is holding the character.
+    #[default]
     Catcher = 1,
     /// The held character.
     Caught = 2,
 }
 
-impl Default for CPointKind {
-    fn default() -> Self {
-        Self::Catcher
-    }
-}
-
 impl FromStr for CPointKind {
     type Err = CPointKindParseError;
 
@@ -123,16 +123,30 @@ pub struct Itr {
     pub injury: i32,
     /// Itr `effect` variants.
     pub effect: Effect,
-    /// Frame numbers for where the catching object should switch to.
+    /// Frame number for where the catching object should switch to, when
+    /// catching a character from the front.
     ///
     /// Used in `itr/kind: 1` ([`ItrKind::CatchStunned`]) and `itr/kind: 3`
     /// ([`ItrKind::CatchForce`]).
     pub catching_act: FrameNumberNext,
-    /// Frame numbers for where the caught character should switch to.
+    /// Frame number for where the catching object should switch to, when
+    /// catching a character from the back.
+    ///
+    /// This is the second `catchingact` value, and is the same as
+    /// `catching_act` when the second value is missing.
+    pub catching_act_back: FrameNumberNext,
+    /// Frame number for where the caught character should switch to, when
+    /// caught from the front.
     ///
     /// Used in `itr/kind: 1` ([`ItrKind::CatchStunned`]) and `itr/kind: 3`
     /// ([`ItrKind::CatchForce`]).
     pub caught_act: FrameNumberNext,
+    /// Frame number for where the caught character should switch to, when
+    /// caught from the back.
+    ///
+    /// This is the second `caughtact` value, and is the same as `caught_act`
+    /// when the second value is missing.
+    pub caught_act_back: FrameNumberNext,
 }
 
 impl Default for Itr {
@@ -153,7 +167,9 @@ impl Default for Itr {
             injury: Default::default(),
             effect: Default::default(),
             catching_act: Default::default(),
+            catching_act_back: Default::default(),
             caught_act: Default::default(),
+            caught_act_back: Default::default(),
         }
     }
 }
@@ -403,15 +419,10 @@ impl Itr {
         mut itr: Itr,
         value_pair: Pair<'i, Rule>,
     ) -> Result<Itr, Error<'i>> {
-        let catching_act = value_pair
-            .as_str()
-            .parse()
-            .map_err(|error| Error::ParseInt {
-                field: stringify!(catching_act),
-                value_pair,
-                error,
-            })?;
+        let (catching_act, catching_act_back) =
+            Self::parse_act_values(stringify!(catching_act), value_pair)?;
         itr.catching_act = catching_act;
+        itr.catching_act_back = catching_act_back;
         Ok(itr)
     }
 
@@ -419,17 +430,32 @@ impl Itr {
         mut itr: Itr,
         value_pair: Pair<'i, Rule>,
     ) -> Result<Itr, Error<'i>> {
-        let caught_act = value_pair
-            .as_str()
-            .parse()
-            .map_err(|error| Error::ParseInt {
-                field: stringify!(caught_act),
-                value_pair,
-                error,
-            })?;
+        let (caught_act, caught_act_back) =
+            Self::parse_act_values(stringify!(caught_act), value_pair)?;
         itr.caught_act = caught_act;
+        itr.caught_act_back = caught_act_back;
         Ok(itr)
     }
+
+    /// Parses the front and back frame numbers of a `catchingact` or
+    /// `caughtact`, using the front value when the back value is missing.
+    fn parse_act_values<'i>(
+        field: &'static str,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<(FrameNumberNext, FrameNumberNext), Error<'i>> {
+        let mut values = value_pair.as_str().split_whitespace();
+        let front = values.next().unwrap_or_default();
+        let back = values.next().unwrap_or(front);
+
+        match (front.parse(), back.parse()) {
+            (Ok(front), Ok(back)) => Ok((front, back)),
+            (Err(error), _) | (_, Err(error)) => Err(Error::ParseInt {
+                field,
+                value_pair,
+                error,
+            }),
+        }
+    }
 }
 
 impl<'i> TryFrom<Pair<'i, Rule>> for Itr {
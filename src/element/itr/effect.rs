@@ -7,7 +7,7 @@ mod effect_parse_error;
 /// Itr `effect` variants.
 ///
 /// See https://lf-empire.de/en/lf2-empire/data-changing/reference-pages/181-effects
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Effect {
     /// Normal, weapons fly away.
     ///
@@ -15,6 +15,7 @@ pub enum Effect {
     /// * **Effect:** Normal, weapons fly away.
     /// * **Examples:** Regular attacks.
     /// * **itrs **without any effect act like this.
+    #[default]
     Normal = 0,
     /// Blood, weapons fly away.
     ///
@@ -93,12 +94,6 @@ pub enum Effect {
     Icicle = 30,
 }
 
-impl Default for Effect {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl FromStr for Effect {
     type Err = EffectParseError;
 
@@ -5,9 +5,10 @@ use crate::ItrKindParseError;
 /// Interaction variants.
 ///
 /// See https://lf-empire.de/lf2-empire/data-changing/frame-elements/174-itr-interaction?showall=1
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ItrKind {
     /// Hit another object's `bdy`.
+    #[default]
     Normal = 0,
     /// Catch a character that is stunned / "dance of pain" (state 16).
     CatchStunned = 1,
@@ -104,12 +105,6 @@ pub enum ItrKind {
     WhirlwindIce = 16,
 }
 
-impl Default for ItrKind {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl FromStr for ItrKind {
     type Err = ItrKindParseError;
 
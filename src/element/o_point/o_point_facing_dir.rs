@@ -1,16 +1,11 @@
 /// Whether the same / opposite of parent, or always to the right.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum OPointFacingDir {
     /// Face the same direction as the parent.
+    #[default]
     ParentSame,
     /// Face the opposite direction to the parent.
     ParentOpposite,
     /// Always face to the right.
     Right,
 }
-
-impl Default for OPointFacingDir {
-    fn default() -> Self {
-        Self::ParentSame
-    }
-}
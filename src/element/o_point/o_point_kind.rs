@@ -5,13 +5,14 @@ use crate::OPointKindParseError;
 /// Object spawning variants.
 ///
 /// See https://lf-empire.de/lf2-empire/data-changing/frame-elements/178-opoint-object-point
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum OPointKind {
     /// Spawns an object on the same team.
     ///
     /// Note that when spawning type: 0 objects (characters), ID 5 (Rudolf) and
     /// ID 52 (Julian) are spawned with 10 HP, and all other IDs are spawned
     /// with 500 HP.
+    #[default]
     Spawn = 1,
     /// Object is spawned and held as a light weapon.
     ///
@@ -19,12 +20,6 @@ pub enum OPointKind {
     HoldLightWeapon = 2,
 }
 
-impl Default for OPointKind {
-    fn default() -> Self {
-        Self::Spawn
-    }
-}
-
 impl FromStr for OPointKind {
     type Err = OPointKindParseError;
 
@@ -36,6 +36,10 @@ pub struct WPoint {
     ///
     /// Leave this at 0 if you don't want to throw the weapon.
     pub d_vy: i64,
+    /// Acceleration on the Z axis to throw the weapon.
+    ///
+    /// Leave this at 0 if you don't want to throw the weapon.
+    pub d_vz: i64,
 }
 
 impl WPoint {
@@ -90,6 +94,9 @@ impl WPoint {
             Rule::TagDVy => {
                 ObjectDataParser::parse_value(w_point, w_point_tag_pair, Self::parse_d_vy_value)?
             }
+            Rule::TagDVz => {
+                ObjectDataParser::parse_value(w_point, w_point_tag_pair, Self::parse_d_vz_value)?
+            }
             _ => w_point,
         };
         Ok(w_point)
@@ -194,6 +201,22 @@ impl WPoint {
         w_point.d_vy = d_vy;
         Ok(w_point)
     }
+
+    fn parse_d_vz_value<'i>(
+        mut w_point: WPoint,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WPoint, Error<'i>> {
+        let d_vz = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(dvz),
+                value_pair,
+                error,
+            })?;
+        w_point.d_vz = d_vz;
+        Ok(w_point)
+    }
 }
 
 impl<'i> TryFrom<Pair<'i, Rule>> for WPoint {
@@ -3,9 +3,10 @@ use std::str::FromStr;
 use crate::WPointKindParseError;
 
 /// Whether this describes holding a weapon, held as one, or dropping one.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum WPointKind {
     /// Indicates the information when holding a weapon.
+    #[default]
     Holding = 1,
     /// Indicates the coordinates when held as a weapon.
     Held = 2,
@@ -13,12 +14,6 @@ pub enum WPointKind {
     Dropping = 3,
 }
 
-impl Default for WPointKind {
-    fn default() -> Self {
-        Self::Holding
-    }
-}
-
 impl FromStr for WPointKind {
     type Err = WPointKindParseError;
 
@@ -11,8 +11,9 @@ use lf2_codec::{DecodeError, EncodeError};
 use pest::iterators::{Pair, Pairs};
 
 use crate::{
-    BdyKindParseError, CPointKindParseError, EffectParseError, FrameNumber, ItrKindParseError,
-    OPointKindParseError, ObjectData, Rule, StateParseError, WPointKindParseError,
+    BdyKindParseError, CPointKindParseError, Diagnostic, EffectParseError, FrameNumber,
+    ItrKindParseError, OPointKindParseError, ObjectData, ObjectTypeParseError, Rule,
+    StateParseError, WPointKindParseError,
 };
 
 #[derive(Debug)]
@@ -41,14 +42,45 @@ pub enum Error<'i> {
         /// The `io::Error` returned by the OS.
         io_error: io::Error,
     },
+    /// Frame numbers are used across multiple frames.
     FrameNumberNonUnique {
-        /// `FrameNumber` that is used across multiple frames.
+        /// Each `FrameNumber` used across multiple frames, with the parsed
+        /// `Pair`s of those frames.
+        frame_pairs_by_number: Vec<(FrameNumber, Vec<Pair<'i, Rule>>)>,
+        /// Diagnostic listing each duplicate set with its line numbers.
+        diagnostic: Diagnostic,
+    },
+    /// A frame number is greater than the maximum allowed by the `FrameLimit`.
+    FrameNumberExceeded {
+        /// `FrameNumber` that is over the limit.
         frame_number: FrameNumber,
-        /// Parsed `Pair`s of the frames with non-unique frame numbers.
-        frame_pairs: Vec<Pair<'i, Rule>>,
+        /// Largest frame number allowed.
+        frame_number_max: FrameNumber,
+        /// Parsed `Pair` of the frame.
+        frame_pair: Pair<'i, Rule>,
+    },
+    /// A known tag within a frame or element has a value that does not match
+    /// its type, such as `wait: x3`.
+    TagValueInvalid {
+        /// Parsed `Pair` of the tag and its value.
+        tag_pair: Pair<'i, Rule>,
+    },
+    /// A tag within a frame or element is not recognized.
+    TagUnknown {
+        /// Parsed `Pair` of the tag and its value.
+        tag_pair: Pair<'i, Rule>,
+    },
+    /// There are more frames than allowed by the `FrameLimit`.
+    FrameCountExceeded {
+        /// Number of frames defined.
+        frame_count: usize,
+        /// Maximum number of frames allowed.
+        frame_count_max: usize,
     },
     /// Data file is not valid UTF8.
     DecodedDataInvalidUtf8(FromUtf8Error),
+    /// Expected to parse `data.txt`, but got nothing.
+    DataTxtExpected,
     /// Expected to parse object data, but got nothing.
     ObjectDataExpected,
     /// `ObjectData` is successfully parsed, but there is surplus data.
@@ -95,6 +127,13 @@ pub enum Error<'i> {
         /// The `OPointKindParseError` from the parse attempt,
         error: OPointKindParseError,
     },
+    /// A pair failed to parse as an `ObjectType`.
+    ParseObjectType {
+        /// The value that failed to be parsed.
+        value_pair: Pair<'i, Rule>,
+        /// The `ObjectTypeParseError` from the parse attempt,
+        error: ObjectTypeParseError,
+    },
     /// Failed to parse `opoint: action:` value as `FrameNumberNext`.
     ParseOPointAction {
         /// The value that failed to be parsed.
@@ -243,36 +282,80 @@ impl<'i> Display for Error<'i> {
                 io_error
             ),
             Self::FrameNumberNonUnique {
-                frame_number,
-                frame_pairs,
+                frame_pairs_by_number,
+                ..
             } => {
-                write!(
+                writeln!(
                     f,
-                    "Frame numbers must only be used once, but `{}` is used multiple times:
-                    \n",
-                    frame_number,
+                    "Frame numbers must only be used once, but the following are used multiple times:"
                 )?;
 
-                frame_pairs.iter().try_for_each(|frame_pair| {
-                    write!(f, "- ")?;
-
-                    if let Some(frame_first_line) = frame_pair.as_str().lines().next() {
-                        write!(f, "`{}` ", frame_first_line)?;
-                    }
+                frame_pairs_by_number
+                    .iter()
+                    .try_for_each(|(frame_number, frame_pairs)| {
+                        writeln!(f)?;
+                        writeln!(f, "`{}`:", frame_number)?;
 
-                    let (line, col) = frame_pair.as_span().start_pos().line_col();
-                    writeln!(f, "at position `{}:{}`", line, col)?;
+                        frame_pairs.iter().try_for_each(|frame_pair| {
+                            write!(f, "- ")?;
 
-                    Ok(())
-                })?;
+                            if let Some(frame_first_line) = frame_pair.as_str().lines().next() {
+                                write!(f, "`{}` ", frame_first_line)?;
+                            }
 
-                writeln!(f)
+                            let (line, col) = frame_pair.as_span().start_pos().line_col();
+                            writeln!(f, "at position `{}:{}`", line, col)
+                        })
+                    })
+            }
+            Self::FrameNumberExceeded {
+                frame_number,
+                frame_number_max,
+                frame_pair,
+            } => {
+                let (line, col) = frame_pair.as_span().start_pos().line_col();
+                write!(
+                    f,
+                    "Frame number `{}` at position `{}:{}` is greater than the maximum of `{}`.",
+                    frame_number, line, col, frame_number_max
+                )
             }
+            Self::TagValueInvalid { tag_pair } => {
+                let (line, col) = tag_pair.as_span().start_pos().line_col();
+                write!(
+                    f,
+                    "Invalid value in tag `{}` at position `{}:{}`.",
+                    tag_pair.as_str(),
+                    line,
+                    col
+                )
+            }
+            Self::TagUnknown { tag_pair } => {
+                let (line, col) = tag_pair.as_span().start_pos().line_col();
+                write!(
+                    f,
+                    "Unknown tag `{}` at position `{}:{}`.",
+                    tag_pair.as_str(),
+                    line,
+                    col
+                )
+            }
+            Self::FrameCountExceeded {
+                frame_count,
+                frame_count_max,
+            } => write!(
+                f,
+                "Object data has `{}` frames, which is more than the maximum of `{}`.",
+                frame_count, frame_count_max
+            ),
             Self::DecodedDataInvalidUtf8(e) => {
                 writeln!(f, "Decoded object data is not valid UTF8.\n\
                     Try redownloading the object. If it doesn't work, then it likely cannot be used.\n\
                     Underlying error: {}", e)
             }
+            Self::DataTxtExpected => {
+                write!(f, "Expected to parse `data.txt`, but got nothing.")
+            }
             Self::ObjectDataExpected => {
                 write!(f, "Expected to parse object data, but got nothing.")
             }
@@ -337,6 +420,15 @@ impl<'i> Display for Error<'i> {
                     value_string, line, col, error
                 )
             }
+            Self::ParseObjectType { value_pair, error } => {
+                let value_string = value_pair.as_str();
+                let (line, col) = value_pair.as_span().start_pos().line_col();
+                write!(
+                    f,
+                    "Failed to parse `type:` value `{}` at position: `{}:{}`. Error: `{}`.",
+                    value_string, line, col, error
+                )
+            }
             Self::ParseOPointAction { value_pair, error } => {
                 let value_string = value_pair.as_str();
                 let (line, col) = value_pair.as_span().start_pos().line_col();
@@ -433,13 +525,13 @@ impl<'i> Display for Error<'i> {
                 if let Some(pair_found) = pair_found {
                     let rule = pair_found.as_rule();
                     let (line, col) = pair_found.as_span().start_pos().line_col();
-                    write!(
+                    writeln!(
                         f,
-                        " at position: `{}:{}`, but grammar parsed a `{:?}`.\n",
+                        " at position: `{}:{}`, but grammar parsed a `{:?}`.",
                         line, col, rule,
                     )?;
                 } else {
-                    write!(f, ", but nothing is found.\n")?;
+                    writeln!(f, ", but nothing is found.")?;
                 }
 
                 write!(
@@ -457,13 +549,13 @@ impl<'i> Display for Error<'i> {
                 if let Some(pair_found) = pair_found {
                     let rule = pair_found.as_rule();
                     let (line, col) = pair_found.as_span().start_pos().line_col();
-                    write!(
+                    writeln!(
                         f,
-                        " at position: `{}:{}`, but grammar parsed a `{:?}`.\n",
+                        " at position: `{}:{}`, but grammar parsed a `{:?}`.",
                         line, col, rule,
                     )?;
                 } else {
-                    write!(f, ", but nothing is found.\n")?;
+                    writeln!(f, ", but nothing is found.")?;
                 }
 
                 write!(
@@ -475,9 +567,9 @@ impl<'i> Display for Error<'i> {
             Self::ValueExpected { tag_pair } => {
                 let rule = tag_pair.as_rule();
                 let (line, col) = tag_pair.as_span().start_pos().line_col();
-                write!(
+                writeln!(
                     f,
-                    "Expected value for the `{:?}` tag at position: `{}:{}`, but nothing is found.\n",
+                    "Expected value for the `{:?}` tag at position: `{}:{}`, but nothing is found.",
                     rule, line, col
                 )?;
 
@@ -7,6 +7,7 @@ use crate::{Element, Error, ObjectDataParser, Rule, SubRuleFn};
 pub use self::{
     frame_number::FrameNumber,
     frame_number_next::FrameNumberNext,
+    mp_cost::MpCost,
     pic::Pic,
     state::{State, StateParseError},
     wait::Wait,
@@ -14,6 +15,7 @@ pub use self::{
 
 mod frame_number;
 mod frame_number_next;
+mod mp_cost;
 mod pic;
 mod state;
 mod wait;
@@ -78,6 +80,29 @@ impl Default for Frame {
 }
 
 impl Frame {
+    /// Returns the MP and HP cost of this frame when reached through a `hit_*`
+    /// tag.
+    ///
+    /// Only positive `mp` values are paid this way, see [`MpCost`].
+    pub fn mp_cost_hit(&self) -> MpCost {
+        if self.mp > 0 {
+            MpCost::from_mp(self.mp)
+        } else {
+            MpCost::NONE
+        }
+    }
+
+    /// Returns the MP and HP cost of this frame when reached through `next`.
+    ///
+    /// Only negative `mp` values are paid this way, see [`MpCost`].
+    pub fn mp_cost_next(&self) -> MpCost {
+        if self.mp < 0 {
+            MpCost::from_mp(self.mp)
+        } else {
+            MpCost::NONE
+        }
+    }
+
     fn parse_number<'i>(
         frame: Frame,
         frame_number_pair: Pair<'i, Rule>,
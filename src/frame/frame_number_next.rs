@@ -16,6 +16,13 @@ use crate::FrameNumber;
 pub struct FrameNumberNext(pub isize);
 
 impl FrameNumberNext {
+    /// Remain on the current frame.
+    pub const NONE: FrameNumberNext = FrameNumberNext(0);
+    /// Return to the standing frame, or `212` when in mid air.
+    pub const STANDING: FrameNumberNext = FrameNumberNext(999);
+    /// Delete the object.
+    pub const DELETE: FrameNumberNext = FrameNumberNext(1000);
+
     /// Returns a positive `FrameNumber`.
     pub fn abs(self) -> FrameNumber {
         let n = TryFrom::<isize>::try_from(self.0.abs()).unwrap_or_else(|e| {
@@ -0,0 +1,92 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
+
+/// MP and HP that a frame costs, decoded from its `mp` value.
+///
+/// The last three digits of `mp` are the MP cost, and the digits above them
+/// are the HP cost in tens. For example, `mp: 1100` costs 100 MP and 10 HP.
+///
+/// Positive values are paid when the frame is reached through a `hit_*` tag,
+/// and the frame is not entered without enough MP. Negative values are paid
+/// when the frame is reached through `next`, and the object switches to the
+/// frame's `hit_d` without enough MP.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MpCost {
+    /// MP that is paid.
+    pub mp: i64,
+    /// HP that is paid.
+    pub hp: i64,
+}
+
+impl MpCost {
+    /// Cost of a frame with `mp: 0`.
+    pub const NONE: Self = Self { mp: 0, hp: 0 };
+
+    /// Returns the cost encoded in a frame's `mp` value, ignoring its sign.
+    ///
+    /// # Parameters
+    ///
+    /// * `mp`: The frame's `mp` value.
+    pub fn from_mp(mp: i64) -> Self {
+        // `unsigned_abs` keeps `i64::MIN` from overflowing. Both costs fit in
+        // an `i64` after dividing by 1000.
+        let mp = mp.unsigned_abs();
+        Self {
+            mp: (mp % 1000) as i64,
+            hp: (mp / 1000 * 10) as i64,
+        }
+    }
+
+    /// Returns whether nothing is paid.
+    pub fn is_none(self) -> bool {
+        self == Self::NONE
+    }
+}
+
+impl Add for MpCost {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            mp: self.mp + other.mp,
+            hp: self.hp + other.hp,
+        }
+    }
+}
+
+impl AddAssign for MpCost {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for MpCost {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::NONE, Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MpCost;
+
+    #[test]
+    fn from_mp_splits_mp_and_hp_ignoring_sign() {
+        assert_eq!(MpCost { mp: 100, hp: 10 }, MpCost::from_mp(1100));
+        assert_eq!(MpCost { mp: 100, hp: 10 }, MpCost::from_mp(-1100));
+        assert_eq!(MpCost::NONE, MpCost::from_mp(0));
+    }
+
+    #[test]
+    fn from_mp_does_not_overflow_on_min() {
+        assert_eq!(
+            MpCost {
+                mp: 808,
+                hp: 92_233_720_368_547_750,
+            },
+            MpCost::from_mp(i64::MIN)
+        );
+    }
+}
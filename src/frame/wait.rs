@@ -6,12 +6,21 @@ use std::{
 };
 
 /// Default wait value of 1.
-pub const WAIT_DEFAULT: Wait = Wait(unsafe { NonZeroU32::new_unchecked(1) });
+pub const WAIT_DEFAULT: Wait = Wait(NonZeroU32::MIN);
 
 /// Represents the frame number.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Wait(pub NonZeroU32);
 
+impl Wait {
+    /// Returns the number of ticks (TU) that a frame with this `wait` lasts.
+    ///
+    /// LF2 holds a frame for `wait + 1` TU before switching to `next`.
+    pub fn ticks(self) -> u32 {
+        self.0.get() + 1
+    }
+}
+
 impl Default for Wait {
     fn default() -> Self {
         WAIT_DEFAULT
@@ -1,84 +1,629 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
-    ops::{Deref, DerefMut},
+    iter::FromIterator,
+    ops::Deref,
 };
 
 use pest::iterators::Pair;
 use tinyvec::TinyVec;
 
-use crate::{Error, Frame, ObjectDataParser, Rule, SubRuleWrapper};
+use crate::{
+    Diagnostic, Element, Error, Frame, FrameLimit, FrameNumber, FrameNumberDuplicateMode,
+    FrameNumberNext, FrameSpan, ItrKind, ObjectDataParser, ParseOptions, Rule, Severity, State,
+    Strictness, SubRuleWrapper,
+};
+
+pub use self::{
+    command_list::CommandList, command_transition::CommandTransition,
+    command_transitions::CommandTransitions, frame_chain_end::FrameChainEnd, frame_mut::FrameMut,
+    frame_timing::FrameTiming, frame_timing_entry::FrameTimingEntry, frames_mut::FramesMut,
+    hit_reaction::HitReaction, move_damage::MoveDamage, move_hit::MoveHit, move_list::MoveList,
+    r#move::Move,
+};
+
+mod command_list;
+mod command_transition;
+mod command_transitions;
+mod frame_chain_end;
+mod frame_mut;
+mod frame_timing;
+mod frame_timing_entry;
+mod frames_mut;
+mod hit_reaction;
+mod r#move;
+mod move_damage;
+mod move_hit;
+mod move_list;
+
+/// Frame pairs, frames, and diagnostics accumulated while parsing.
+type FramesBuilder<'i> = (Vec<Pair<'i, Rule>>, Vec<Frame>, Vec<Diagnostic>);
 
-/// `Vec<Frame>` newtype.
+/// Frames of an object, in file order, indexed by frame number.
+///
+/// Each frame number is used by at most one frame. Frames are looked up
+/// through an index that is kept consistent as frames are inserted and
+/// removed.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct Frames(pub Vec<Frame>);
+pub struct Frames {
+    /// Frames in file order.
+    frames: Vec<Frame>,
+    /// Position of each frame in `frames`, by frame number.
+    indices: BTreeMap<FrameNumber, usize>,
+}
 
 impl Frames {
-    /// Maximum number of frames that a data file may have.
+    /// Returns an empty `Frames`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the frame with the given number.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Number of the frame to return.
+    pub fn get(&self, frame_number: FrameNumber) -> Option<&Frame> {
+        self.indices
+            .get(&frame_number)
+            .map(|index| &self.frames[*index])
+    }
+
+    /// Returns a mutable reference to the frame with the given number.
+    ///
+    /// The frames are re-indexed when the reference is dropped, see
+    /// [`Frames::frames_mut`].
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Number of the frame to return.
+    pub fn get_mut(&mut self, frame_number: FrameNumber) -> Option<FrameMut<'_>> {
+        let index = *self.indices.get(&frame_number)?;
+        Some(FrameMut {
+            frames: self,
+            index,
+        })
+    }
+
+    /// Returns whether there is a frame with the given number.
+    ///
+    /// # Parameters
     ///
-    /// LF2 has a hard limit of 400 by default. There are exe modifications that
-    /// increase this, but we will not support this for now.
-    const FRAME_COUNT_MAX: usize = 400;
+    /// * `frame_number`: Number of the frame to look for.
+    pub fn contains(&self, frame_number: FrameNumber) -> bool {
+        self.indices.contains_key(&frame_number)
+    }
+
+    /// Returns the frame that a `next` or `hit_*` value switches to.
+    ///
+    /// `999` resolves to frame `0`, as the object returns to standing. Values
+    /// that do not switch to a frame, `0` and `1000`, resolve to `None`.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number_next`: Value to resolve.
+    pub fn resolve(&self, frame_number_next: FrameNumberNext) -> Option<&Frame> {
+        match frame_number_next {
+            FrameNumberNext::NONE | FrameNumberNext::DELETE => None,
+            FrameNumberNext::STANDING => self.get(FrameNumber(0)),
+            frame_number_next => self.get(frame_number_next.abs()),
+        }
+    }
+
+    /// Inserts a frame, returning the frame it replaces.
+    ///
+    /// A frame that replaces another with the same number takes its position,
+    /// otherwise it is added after the last frame.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame`: Frame to insert.
+    pub fn insert(&mut self, frame: Frame) -> Option<Frame> {
+        match self.indices.get(&frame.number) {
+            Some(index) => Some(std::mem::replace(&mut self.frames[*index], frame)),
+            None => {
+                self.indices.insert(frame.number, self.frames.len());
+                self.frames.push(frame);
+                None
+            }
+        }
+    }
+
+    /// Removes and returns the frame with the given number.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Number of the frame to remove.
+    pub fn remove(&mut self, frame_number: FrameNumber) -> Option<Frame> {
+        let index = self.indices.remove(&frame_number)?;
+        self.indices
+            .values_mut()
+            .filter(|index_other| **index_other > index)
+            .for_each(|index_other| *index_other -= 1);
+        Some(self.frames.remove(index))
+    }
+
+    /// Returns the frames as a mutable slice, in file order.
+    ///
+    /// The frames are re-indexed when the slice is dropped. If frames are
+    /// renumbered so that numbers clash, the later frame replaces the earlier
+    /// one and takes its position, as in [`Frames::from`].
+    pub fn frames_mut(&mut self) -> FramesMut<'_> {
+        FramesMut { frames: self }
+    }
+
+    /// Rebuilds the index if a frame's number no longer matches it.
+    fn reindex(&mut self) {
+        let indexed = self.frames.len() == self.indices.len()
+            && self
+                .frames
+                .iter()
+                .enumerate()
+                .all(|(index, frame)| self.indices.get(&frame.number) == Some(&index));
+        if !indexed {
+            *self = std::mem::take(&mut self.frames).into_iter().collect();
+        }
+    }
+
+    /// Returns the commands that the object accepts, and where they lead.
+    pub fn commands(&self) -> CommandList {
+        CommandList::new(self)
+    }
+
+    /// Returns the estimated damage of the move entered at a frame, hit by
+    /// hit.
+    ///
+    /// See [`MoveDamage`] for how hits are estimated.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Frame that the move is entered from.
+    /// * `target`: Frames of the character that is hit.
+    pub fn damage(&self, frame_number: FrameNumber, target: &Frames) -> Option<MoveDamage> {
+        MoveDamage::new(self, frame_number, target)
+    }
+
+    /// Returns the moves of the object, grouping consecutive frames with the
+    /// same name.
+    pub fn moves(&self) -> MoveList {
+        MoveList::new(self)
+    }
+
+    /// Returns the timing of the chain of `next` frames from a frame.
+    ///
+    /// The chain ends when it returns to standing, reaches a standing,
+    /// walking, or running frame, or cannot continue. Returns `None` if the
+    /// starting frame does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_number`: Frame that the chain begins from.
+    pub fn timing(&self, frame_number: FrameNumber) -> Option<FrameTiming> {
+        let mut frame = self.get(frame_number)?;
+        let mut entries = Vec::<FrameTimingEntry>::new();
+        let mut start_tick = 0;
+
+        let end = loop {
+            let ticks = frame.wait.ticks();
+            entries.push(FrameTimingEntry {
+                frame_number: frame.number,
+                name: frame.name.clone(),
+                start_tick,
+                ticks,
+                active: Self::frame_active(frame),
+            });
+            start_tick += ticks;
+
+            let frame_number_next = match frame.next_frame {
+                FrameNumberNext::NONE => break FrameChainEnd::Hold,
+                FrameNumberNext::STANDING => break FrameChainEnd::Standing,
+                FrameNumberNext::DELETE => break FrameChainEnd::Deleted,
+                next => next.abs(),
+            };
+            if entries
+                .iter()
+                .any(|entry| entry.frame_number == frame_number_next)
+            {
+                break FrameChainEnd::Loop(frame_number_next);
+            }
+
+            frame = match self.get(frame_number_next) {
+                Some(frame) => frame,
+                None => break FrameChainEnd::Missing(frame_number_next),
+            };
+            if let State::Standing | State::Walking | State::Running = frame.state {
+                break FrameChainEnd::Neutral(frame_number_next);
+            }
+        };
+
+        Some(FrameTiming { entries, end })
+    }
+
+    /// Returns whether a frame has an `itr` that hits other objects.
+    ///
+    /// Catching, picking up weapons, enabling super punches, healing, and
+    /// walls do not hit.
+    fn frame_active(frame: &Frame) -> bool {
+        frame.elements.iter().any(|element| match element {
+            Element::Itr(itr) => matches!(
+                itr.kind,
+                ItrKind::Normal
+                    | ItrKind::Falling
+                    | ItrKind::WeaponStrength
+                    | ItrKind::ReflectiveShield
+                    | ItrKind::SonataOfDeath
+                    | ItrKind::SonataOfDeath2
+                    | ItrKind::WhirlwindWind
+                    | ItrKind::WhirlwindIce
+            ),
+            _ => false,
+        })
+    }
+
+    /// Parses frames, following the given options.
+    ///
+    /// Returns the frames, warnings for any problems that the options allow,
+    /// and where each frame is if spans are kept.
+    pub(crate) fn parse_with_options<'i>(
+        pair: Pair<'i, Rule>,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Diagnostic>, Vec<FrameSpan>), Error<'i>> {
+        let frame_count_capacity = FrameLimit::LF2_V2_0.frame_count_max;
+        let frame_pairs = Vec::<Pair<'i, Rule>>::with_capacity(frame_count_capacity);
+        let frames = Vec::<Frame>::with_capacity(frame_count_capacity);
+        let parse_frame = SubRuleWrapper::new(|builder: FramesBuilder<'i>, frame_pair| {
+            Self::parse_frame(builder, frame_pair, options)
+        });
+        let (frame_pairs, frames, mut diagnostics) = ObjectDataParser::parse_as_type(
+            (frame_pairs, frames, Vec::new()),
+            pair,
+            Rule::Frames,
+            std::iter::repeat(&parse_frame),
+        )?;
+        let diagnostics_limit = Self::limit_check(&frame_pairs, &frames, options)?;
+        diagnostics.extend(diagnostics_limit);
+        let (frame_pairs, frames) =
+            Self::validate((frame_pairs, frames), options, &mut diagnostics)?;
+
+        let frame_spans = if options.spans_keep {
+            frame_pairs
+                .iter()
+                .zip(frames.iter())
+                .map(|(frame_pair, frame)| FrameSpan::new(frame.number, frame_pair.as_span()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok((Frames::from(frames), diagnostics, frame_spans))
+    }
 
     fn parse_frame<'i>(
-        (mut frame_pairs, mut frames): (Vec<Pair<'i, Rule>>, Frames),
+        (mut frame_pairs, mut frames, mut diagnostics): FramesBuilder<'i>,
         frame_pair: Pair<'i, Rule>,
-    ) -> Result<(Vec<Pair<'i, Rule>>, Frames), Error<'i>> {
+        options: &ParseOptions,
+    ) -> Result<FramesBuilder<'i>, Error<'i>> {
+        let frame_number = Self::frame_number_of(&frame_pair);
+
+        let tag_pairs_unknown = frame_pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .filter(|pair| pair.as_rule() == Rule::TagUnknown);
+        for tag_pair in tag_pairs_unknown {
+            let error = Error::TagUnknown { tag_pair };
+            match options.tags_unknown {
+                Strictness::Strict => return Err(error),
+                Strictness::Lenient => diagnostics.push(Diagnostic::new(
+                    ParseOptions::TAG_UNKNOWN,
+                    Severity::Warning,
+                    frame_number,
+                    format!("{} The tag is ignored.", error),
+                )),
+            }
+        }
+
+        // Known tags whose value does not match their rule are not parsed by
+        // `Frame` or `Element`, so they are checked here.
+        let tag_pairs_value_invalid = frame_pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .filter(|pair| pair.as_rule() == Rule::TagValueInvalid);
+        for tag_pair in tag_pairs_value_invalid {
+            let error = Error::TagValueInvalid { tag_pair };
+            match options.values_invalid {
+                Strictness::Strict => return Err(error),
+                Strictness::Lenient => diagnostics.push(Diagnostic::new(
+                    ParseOptions::VALUE_INVALID,
+                    Severity::Warning,
+                    frame_number,
+                    format!("{} The tag is ignored.", error),
+                )),
+            }
+        }
+
+        // `Frame` skips elements that fail to parse, so they are checked here.
+        let element_errors = frame_pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .filter(|pair| pair.as_rule() == Rule::Element)
+            .filter_map(|element_pair| Element::try_from(element_pair).err());
+        for error in element_errors {
+            match options.values_invalid {
+                Strictness::Strict => return Err(error),
+                Strictness::Lenient => diagnostics.push(Diagnostic::new(
+                    ParseOptions::VALUE_INVALID,
+                    Severity::Warning,
+                    frame_number,
+                    format!("{} The element is skipped.", error),
+                )),
+            }
+        }
+
         let frame_pair_clone = frame_pair.clone();
-        if let Ok(frame) = Frame::try_from(frame_pair) {
-            frame_pairs.push(frame_pair_clone);
-            frames.push(frame);
+        match Frame::try_from(frame_pair) {
+            Ok(frame) => {
+                frame_pairs.push(frame_pair_clone);
+                frames.push(frame);
+            }
+            Err(error) => match options.values_invalid {
+                Strictness::Strict => return Err(error),
+                Strictness::Lenient => diagnostics.push(Diagnostic::new(
+                    ParseOptions::VALUE_INVALID,
+                    Severity::Warning,
+                    frame_number,
+                    format!("{} The frame is skipped.", error),
+                )),
+            },
         }
 
-        Ok((frame_pairs, frames))
+        Ok((frame_pairs, frames, diagnostics))
+    }
+
+    /// Returns the frame number of a frame `Pair`, even if the frame fails to
+    /// parse.
+    fn frame_number_of(frame_pair: &Pair<'_, Rule>) -> Option<FrameNumber> {
+        frame_pair
+            .clone()
+            .into_inner()
+            .find(|pair| pair.as_rule() == Rule::FrameNumber)
+            .and_then(|frame_number_pair| frame_number_pair.as_str().trim().parse().ok())
+    }
+
+    fn limit_check<'i>(
+        frame_pairs: &[Pair<'i, Rule>],
+        frames: &[Frame],
+        options: &ParseOptions,
+    ) -> Result<Vec<Diagnostic>, Error<'i>> {
+        let FrameLimit {
+            frame_number_max,
+            frame_count_max,
+        } = options.frame_limit;
+        let mut diagnostics = Vec::new();
+
+        let frames_exceeded = frame_pairs
+            .iter()
+            .zip(frames.iter())
+            .filter(|(_, frame)| frame.number > frame_number_max);
+        for (frame_pair, frame) in frames_exceeded {
+            match options.frame_limit_strictness {
+                Strictness::Strict => {
+                    return Err(Error::FrameNumberExceeded {
+                        frame_number: frame.number,
+                        frame_number_max,
+                        frame_pair: frame_pair.clone(),
+                    });
+                }
+                Strictness::Lenient => {
+                    let (line, _) = frame_pair.as_span().start_pos().line_col();
+                    diagnostics.push(Diagnostic::new(
+                        FrameLimit::FRAME_NUMBER_EXCEEDED,
+                        Severity::Warning,
+                        Some(frame.number),
+                        format!(
+                            "Frame number at line {} is greater than the maximum of `{}`.",
+                            line, frame_number_max
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let frame_count = frames.len();
+        if frame_count > frame_count_max {
+            match options.frame_limit_strictness {
+                Strictness::Strict => {
+                    return Err(Error::FrameCountExceeded {
+                        frame_count,
+                        frame_count_max,
+                    });
+                }
+                Strictness::Lenient => diagnostics.push(Diagnostic::new(
+                    FrameLimit::FRAME_COUNT_EXCEEDED,
+                    Severity::Warning,
+                    None,
+                    format!(
+                        "There are `{}` frames, which is more than the maximum of `{}`.",
+                        frame_count, frame_count_max
+                    ),
+                )),
+            }
+        }
+
+        Ok(diagnostics)
     }
 
     fn validate<'i>(
-        (frame_pairs, frames): (Vec<Pair<'i, Rule>>, Frames),
-    ) -> Result<Self, Error<'i>> {
-        let frame_number_indiceses = frames.0.iter().enumerate().fold(
+        (frame_pairs, frames): (Vec<Pair<'i, Rule>>, Vec<Frame>),
+        options: &ParseOptions,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<(Vec<Pair<'i, Rule>>, Vec<Frame>), Error<'i>> {
+        let indices_by_frame_number = frames.iter().enumerate().fold(
             BTreeMap::new(),
-            |mut frame_number_indiceses, (index, frame)| {
-                let frame_number_indices = frame_number_indiceses
+            |mut indices_by_frame_number, (index, frame)| {
+                let frame_number_indices = indices_by_frame_number
                     .entry(frame.number)
                     .or_insert_with(TinyVec::<[usize; 2]>::default);
                 frame_number_indices.reserve(1);
                 frame_number_indices.push(index);
-                frame_number_indiceses
+                indices_by_frame_number
             },
         );
-        frame_number_indiceses
+        let frame_number_duplicates = indices_by_frame_number
             .into_iter()
-            .find(|(_, frame_number_indices)| frame_number_indices.len() > 1)
+            .filter(|(_, frame_number_indices)| frame_number_indices.len() > 1)
+            .collect::<Vec<(FrameNumber, TinyVec<[usize; 2]>)>>();
+        if frame_number_duplicates.is_empty() {
+            return Ok((frame_pairs, frames));
+        }
+
+        let index_kept = match options.frame_number_duplicates {
+            FrameNumberDuplicateMode::Error => |_: &[usize]| None,
+            FrameNumberDuplicateMode::WarnKeepFirst => |indices: &[usize]| indices.first().copied(),
+            FrameNumberDuplicateMode::WarnKeepLast => |indices: &[usize]| indices.last().copied(),
+        };
+
+        let line = |index: usize| {
+            frame_pairs
+                .get(index)
+                .map(|frame_pair| frame_pair.as_span().start_pos().line_col().0)
+                .unwrap_or_default()
+        };
+        let mut indices_removed = BTreeSet::new();
+        let duplicate_sets = frame_number_duplicates
+            .iter()
             .map(|(frame_number, frame_number_indices)| {
-                // Find the pairs corresponding to this frame number.
-                // The frame numbers in the map should be exactly the same as the pairs in the
-                // `frame_pairs` vector.
-                let frame_pairs_non_unique = frame_number_indices
-                    .into_iter()
-                    .filter_map(|index| frame_pairs.get(index).cloned())
-                    .collect::<Vec<Pair<'i, Rule>>>();
-
-                Err(Error::FrameNumberNonUnique {
-                    frame_number,
-                    frame_pairs: frame_pairs_non_unique,
-                })
+                let index_kept = index_kept(frame_number_indices);
+                indices_removed.extend(
+                    frame_number_indices
+                        .iter()
+                        .copied()
+                        .filter(|index| Some(*index) != index_kept),
+                );
+
+                let lines = frame_number_indices
+                    .iter()
+                    .map(|index| line(*index).to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                match index_kept {
+                    Some(index_kept) => format!(
+                        "`{}` at lines {} (kept line {})",
+                        frame_number,
+                        lines,
+                        line(index_kept)
+                    ),
+                    None => format!("`{}` at lines {}", frame_number, lines),
+                }
             })
-            .unwrap_or_else(|| Ok(frames))
+            .collect::<Vec<String>>()
+            .join("; ");
+
+        let (severity, resolution) = match options.frame_number_duplicates {
+            FrameNumberDuplicateMode::Error => (Severity::Error, ""),
+            FrameNumberDuplicateMode::WarnKeepFirst => {
+                (Severity::Warning, ", keeping the first of each")
+            }
+            FrameNumberDuplicateMode::WarnKeepLast => {
+                (Severity::Warning, ", keeping the last of each")
+            }
+        };
+        let diagnostic = Diagnostic::new(
+            ParseOptions::FRAME_NUMBER_DUPLICATE,
+            severity,
+            None,
+            format!(
+                "Frame numbers are used by multiple frames{}: {}.",
+                resolution, duplicate_sets
+            ),
+        );
+
+        if options.frame_number_duplicates == FrameNumberDuplicateMode::Error {
+            // Find the pairs corresponding to each frame number.
+            // The frame numbers in the map should be exactly the same as the pairs in the
+            // `frame_pairs` vector.
+            let frame_pairs_by_number = frame_number_duplicates
+                .into_iter()
+                .map(|(frame_number, frame_number_indices)| {
+                    let frame_pairs_non_unique = frame_number_indices
+                        .into_iter()
+                        .filter_map(|index| frame_pairs.get(index).cloned())
+                        .collect::<Vec<Pair<'i, Rule>>>();
+                    (frame_number, frame_pairs_non_unique)
+                })
+                .collect();
+
+            return Err(Error::FrameNumberNonUnique {
+                frame_pairs_by_number,
+                diagnostic,
+            });
+        }
+        diagnostics.push(diagnostic);
+
+        let (frame_pairs, frames) = frame_pairs
+            .into_iter()
+            .zip(frames)
+            .enumerate()
+            .filter(|(index, _)| !indices_removed.contains(index))
+            .map(|(_, frame_pair_and_frame)| frame_pair_and_frame)
+            .unzip::<_, _, Vec<Pair<'i, Rule>>, Vec<Frame>>();
+
+        Ok((frame_pairs, frames))
     }
 }
 
 impl Deref for Frames {
-    type Target = Vec<Frame>;
+    type Target = [Frame];
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.frames
+    }
+}
+
+impl From<Frames> for Vec<Frame> {
+    /// Returns the frames in file order.
+    fn from(frames: Frames) -> Self {
+        frames.frames
+    }
+}
+
+impl From<Vec<Frame>> for Frames {
+    /// Returns `Frames` in the given order.
+    ///
+    /// A frame with the same number as an earlier frame replaces it, as in
+    /// LF2.
+    fn from(frames: Vec<Frame>) -> Self {
+        frames.into_iter().collect()
     }
 }
 
-impl DerefMut for Frames {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl FromIterator<Frame> for Frames {
+    fn from_iter<I: IntoIterator<Item = Frame>>(iter: I) -> Self {
+        let mut frames = Frames::new();
+        iter.into_iter().for_each(|frame| {
+            frames.insert(frame);
+        });
+        frames
+    }
+}
+
+impl<'f> IntoIterator for &'f Frames {
+    type Item = &'f Frame;
+    type IntoIter = std::slice::Iter<'f, Frame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.iter()
+    }
+}
+
+impl IntoIterator for Frames {
+    type Item = Frame;
+    type IntoIter = std::vec::IntoIter<Frame>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.into_iter()
     }
 }
 
@@ -86,14 +631,126 @@ impl<'i> TryFrom<Pair<'i, Rule>> for Frames {
     type Error = Error<'i>;
 
     fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
-        let frame_pairs = Vec::<Pair<'i, Rule>>::with_capacity(Self::FRAME_COUNT_MAX);
-        let frames = Frames(Vec::with_capacity(Self::FRAME_COUNT_MAX));
-        let frame_pairs_and_frames = ObjectDataParser::parse_as_type(
-            (frame_pairs, frames),
-            pair,
-            Rule::Frames,
-            Iterator::cycle([SubRuleWrapper::new(Self::parse_frame)].iter()),
-        )?;
-        Self::validate(frame_pairs_and_frames)
+        Self::parse_with_options(pair, &ParseOptions::default()).map(|(frames, ..)| frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::Frames;
+    use crate::{Frame, FrameChainEnd, FrameNumber, ObjectData};
+
+    fn frames(frame_numbers: &[usize]) -> Frames {
+        frame_numbers
+            .iter()
+            .map(|frame_number| Frame {
+                number: FrameNumber(*frame_number),
+                name: format!("frame_{}", frame_number),
+                ..Frame::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn get_mut_reindexes_renumbered_frame() {
+        let mut frames = frames(&[0, 1, 2]);
+
+        if let Some(mut frame) = frames.get_mut(FrameNumber(1)) {
+            frame.number = FrameNumber(10);
+        }
+
+        assert!(!frames.contains(FrameNumber(1)));
+        assert_eq!(
+            Some("frame_1"),
+            frames.get(FrameNumber(10)).map(|frame| frame.name.as_str())
+        );
+    }
+
+    #[test]
+    fn frames_mut_clash_keeps_later_frame_at_earlier_position() {
+        let mut frames = frames(&[0, 1, 2]);
+
+        frames.frames_mut()[2].number = FrameNumber(0);
+
+        let names = frames
+            .iter()
+            .map(|frame| frame.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(vec!["frame_2", "frame_1"], names);
+        assert_eq!(
+            Some("frame_2"),
+            frames.get(FrameNumber(0)).map(|frame| frame.name.as_str())
+        );
+    }
+
+    fn frames_timed() -> Frames {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 1 walking\n  pic: 0  state: 1  wait: 1  next: 1\n<frame_end>\n\
+            <frame> 10 punch\n  pic: 0  state: 3  wait: 1  next: 11\n<frame_end>\n\
+            <frame> 11 punch\n  pic: 0  state: 3  wait: 2  next: 12\n\
+            \x20  itr:\n      kind: 0  x: 40  y: 16  w: 25  h: 65  injury: 30\n   itr_end:\n\
+            <frame_end>\n\
+            <frame> 12 punch\n  pic: 0  state: 3  wait: 1  next: 13\n\
+            \x20  itr:\n      kind: 1  x: 40  y: 16  w: 25  h: 65\n\
+            \x20     catchingact: 120 120  caughtact: 130 130\n   itr_end:\n\
+            <frame_end>\n\
+            <frame> 13 punch\n  pic: 0  state: 3  wait: 1  next: 999\n<frame_end>\n\
+            <frame> 20 spin\n  pic: 0  wait: 1  next: 21\n<frame_end>\n\
+            <frame> 21 spin\n  pic: 0  wait: 1  next: -20\n<frame_end>\n\
+            <frame> 30 broken\n  pic: 0  wait: 1  next: 31\n<frame_end>\n\
+            <frame> 40 stop\n  pic: 0  wait: 3  next: 1\n<frame_end>\n";
+        ObjectData::try_from(object_data_str).unwrap().frames
+    }
+
+    #[test]
+    fn timing_of_chain_counts_only_hitting_itrs_as_active() {
+        let timing = frames_timed().timing(FrameNumber(10)).unwrap();
+
+        assert_eq!(
+            vec![
+                (10, 0, 2, false),
+                (11, 2, 3, true),
+                (12, 5, 2, false),
+                (13, 7, 2, false)
+            ],
+            timing
+                .entries
+                .iter()
+                .map(|entry| (
+                    *entry.frame_number,
+                    entry.start_tick,
+                    entry.ticks,
+                    entry.active
+                ))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(2), timing.startup());
+        assert_eq!(3, timing.active());
+        assert_eq!(4, timing.recovery());
+        assert_eq!(9, timing.duration());
+        assert_eq!(FrameChainEnd::Standing, timing.end);
+    }
+
+    #[test]
+    fn timing_ends_on_loop_missing_and_neutral_frames() {
+        let frames = frames_timed();
+
+        [
+            (20, FrameChainEnd::Loop(FrameNumber(20)), 2),
+            (30, FrameChainEnd::Missing(FrameNumber(31)), 1),
+            (40, FrameChainEnd::Neutral(FrameNumber(1)), 1),
+            (1, FrameChainEnd::Loop(FrameNumber(1)), 1),
+        ]
+        .iter()
+        .for_each(|(frame_number, end_expected, entry_count)| {
+            let timing = frames.timing(FrameNumber(*frame_number)).unwrap();
+
+            assert_eq!(*end_expected, timing.end, "frame {}", frame_number);
+            assert_eq!(*entry_count, timing.entries.len(), "frame {}", frame_number);
+            assert_eq!(None, timing.startup());
+        });
+        assert_eq!(None, frames.timing(FrameNumber(99)));
     }
 }
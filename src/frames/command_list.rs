@@ -0,0 +1,147 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{Command, CommandTransitions, FrameNumber, Frames};
+
+/// Commands that an object accepts, and where they lead.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommandList(pub Vec<CommandTransitions>);
+
+impl CommandList {
+    /// Returns the transitions of each command that is accepted by at least
+    /// one frame, in the order of [`Command::ALL`].
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    pub fn new(frames: &Frames) -> Self {
+        let command_transitions = IntoIterator::into_iter(Command::ALL)
+            .map(|command| CommandTransitions::new(frames, command))
+            .filter(|command_transitions| !command_transitions.transitions.is_empty())
+            .collect();
+
+        CommandList(command_transitions)
+    }
+
+    /// Returns the commands that lead to different moves depending on the
+    /// standing frame they are entered in.
+    ///
+    /// See [`CommandTransitions::standing_conflict`].
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    pub fn standing_conflicts(&self, frames: &Frames) -> Vec<CommandTransitions> {
+        self.0
+            .iter()
+            .filter_map(|command_transitions| command_transitions.standing_conflict(frames))
+            .collect()
+    }
+
+    /// Returns frame numbers as comma separated ranges, e.g. `0-3, 7`.
+    fn frame_ranges(frame_numbers: &[FrameNumber]) -> String {
+        let mut frame_numbers = frame_numbers.to_vec();
+        frame_numbers.sort_unstable();
+        frame_numbers.dedup();
+
+        let mut ranges = Vec::<(FrameNumber, FrameNumber)>::new();
+        frame_numbers
+            .into_iter()
+            .for_each(|frame_number| match ranges.last_mut() {
+                Some((_, last)) if last.0 + 1 == frame_number.0 => *last = frame_number,
+                _ => ranges.push((frame_number, frame_number)),
+            });
+
+        ranges
+            .into_iter()
+            .map(|(first, last)| {
+                if first == last {
+                    first.to_string()
+                } else {
+                    format!("{}-{}", first, last)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+impl Deref for CommandList {
+    type Target = Vec<CommandTransitions>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CommandList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for CommandList {
+    /// Writes a command list table in Markdown.
+    ///
+    /// Each row is one frame that a command leads to, with the frames that
+    /// accept the command and lead there.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "| Command | Tag | Next | From frames |")?;
+        writeln!(f, "| :------ | :-- | ---: | :---------- |")?;
+        self.0.iter().try_for_each(|command_transitions| {
+            let command = command_transitions.command;
+            command_transitions.targets().into_iter().try_for_each(
+                |(frame_number_next, frame_numbers)| {
+                    writeln!(
+                        f,
+                        "| `{}` | `{}` | {} | {} |",
+                        command,
+                        command.tag(),
+                        frame_number_next,
+                        Self::frame_ranges(&frame_numbers)
+                    )
+                },
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::CommandList;
+    use crate::{Command, FrameNumber, ObjectData};
+
+    #[test]
+    fn standing_frames_leading_to_different_moves_conflict() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 standing\n  pic: 0  state: 0  wait: 1  next: 1  hit_Fa: 10  hit_Da: 10\n<frame_end>\n\
+            <frame> 1 standing\n  pic: 0  state: 0  wait: 1  next: 0  hit_Fa: 20  hit_Da: 10\n<frame_end>\n\
+            <frame> 10 ball\n  pic: 0  state: 3  wait: 1  next: 999\n<frame_end>\n\
+            <frame> 20 blast\n  pic: 0  state: 3  wait: 1  next: 999\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+        let frames = &object_data.frames;
+
+        let command_list = CommandList::new(frames);
+        let commands = command_list
+            .iter()
+            .map(|command_transitions| command_transitions.command)
+            .collect::<Vec<Command>>();
+        assert_eq!(vec![Command::ForwardAttack, Command::DownAttack], commands);
+
+        let standing_conflicts = command_list.standing_conflicts(frames);
+        assert_eq!(1, standing_conflicts.len());
+        assert_eq!(Command::ForwardAttack, standing_conflicts[0].command);
+        assert_eq!(
+            vec![FrameNumber(0), FrameNumber(1)],
+            standing_conflicts[0]
+                .transitions
+                .iter()
+                .map(|transition| transition.frame_number)
+                .collect::<Vec<FrameNumber>>()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{FrameNumber, FrameNumberNext};
+
+/// Frame that a command is accepted in, and the frame it switches to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommandTransition {
+    /// Frame that accepts the command.
+    pub frame_number: FrameNumber,
+    /// Frame that the command switches to.
+    ///
+    /// Negative values switch to the frame and turn around.
+    pub frame_number_next: FrameNumberNext,
+}
@@ -0,0 +1,107 @@
+use crate::{Command, CommandTransition, FrameNumber, FrameNumberNext, Frames, State};
+
+/// Frames that accept a command, and where the command leads from each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandTransitions {
+    /// The command.
+    pub command: Command,
+    /// Frames that accept the command, in file order.
+    pub transitions: Vec<CommandTransition>,
+}
+
+impl CommandTransitions {
+    /// Returns the frames that accept a command.
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    /// * `command`: Command to collect the transitions of.
+    pub fn new(frames: &Frames, command: Command) -> Self {
+        let transitions = frames
+            .iter()
+            .filter_map(|frame| {
+                let frame_number_next = command.frame_number_next(frame);
+                if frame_number_next == FrameNumberNext::NONE {
+                    None
+                } else {
+                    Some(CommandTransition {
+                        frame_number: frame.number,
+                        frame_number_next,
+                    })
+                }
+            })
+            .collect();
+
+        CommandTransitions {
+            command,
+            transitions,
+        }
+    }
+
+    /// Returns each distinct frame that the command switches to, with the
+    /// frames that switch to it.
+    ///
+    /// Targets are in the order they are first used.
+    pub fn targets(&self) -> Vec<(FrameNumberNext, Vec<FrameNumber>)> {
+        self.transitions.iter().fold(
+            Vec::<(FrameNumberNext, Vec<FrameNumber>)>::new(),
+            |mut targets, transition| {
+                match targets
+                    .iter_mut()
+                    .find(|(target, _)| *target == transition.frame_number_next)
+                {
+                    Some((_, frame_numbers)) => frame_numbers.push(transition.frame_number),
+                    None => {
+                        targets.push((transition.frame_number_next, vec![transition.frame_number]))
+                    }
+                }
+                targets
+            },
+        )
+    }
+
+    /// Returns the transitions from standing frames, if they lead to
+    /// different moves.
+    ///
+    /// A command usually leads to the same move from every standing frame.
+    /// When it does not, the move that comes out depends on which frame of
+    /// the standing animation the command is entered in. Moves are told apart
+    /// by the name of the frame that the command switches to.
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    pub fn standing_conflict(&self, frames: &Frames) -> Option<CommandTransitions> {
+        let transitions = self
+            .transitions
+            .iter()
+            .copied()
+            .filter(|transition| {
+                frames
+                    .get(transition.frame_number)
+                    .map(|frame| frame.state == State::Standing)
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<CommandTransition>>();
+
+        let move_name = |transition: &CommandTransition| match transition.frame_number_next {
+            FrameNumberNext::STANDING | FrameNumberNext::DELETE => None,
+            frame_number_next => frames
+                .get(frame_number_next.abs())
+                .map(|frame| frame.name.as_str()),
+        };
+        let conflicting = transitions.split_first().is_some_and(|(first, rest)| {
+            rest.iter()
+                .any(|transition| move_name(transition) != move_name(first))
+        });
+
+        if conflicting {
+            Some(CommandTransitions {
+                command: self.command,
+                transitions,
+            })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt::{self, Display};
+
+use crate::FrameNumber;
+
+/// How a chain of `next` frames ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameChainEnd {
+    /// The last frame has `next: 999`, returning to standing.
+    Standing,
+    /// The chain reached a standing, walking, or running frame.
+    ///
+    /// The neutral frame is not part of the chain.
+    Neutral(FrameNumber),
+    /// The last frame has `next: 1000`, deleting the object.
+    Deleted,
+    /// The last frame has `next: 0`, staying on that frame until something
+    /// else switches the frame.
+    Hold,
+    /// The chain goes back to a frame that is already part of it.
+    Loop(FrameNumber),
+    /// The chain goes to a frame that does not exist.
+    Missing(FrameNumber),
+}
+
+impl Display for FrameChainEnd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Standing => write!(f, "standing"),
+            Self::Neutral(frame_number) => write!(f, "neutral frame {}", frame_number),
+            Self::Deleted => write!(f, "deleted"),
+            Self::Hold => write!(f, "holds last frame"),
+            Self::Loop(frame_number) => write!(f, "loops to frame {}", frame_number),
+            Self::Missing(frame_number) => write!(f, "missing frame {}", frame_number),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Frame, Frames};
+
+/// Mutable reference to a frame in [`Frames`].
+///
+/// The frames are re-indexed when this is dropped, so the frame's `number` may
+/// be changed. See [`Frames::frames_mut`] for how clashing numbers are handled.
+#[derive(Debug)]
+pub struct FrameMut<'f> {
+    /// Frames that the frame belongs to.
+    pub(super) frames: &'f mut Frames,
+    /// Position of the frame in file order.
+    pub(super) index: usize,
+}
+
+impl Deref for FrameMut<'_> {
+    type Target = Frame;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frames.frames[self.index]
+    }
+}
+
+impl DerefMut for FrameMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.frames.frames[self.index]
+    }
+}
+
+impl Drop for FrameMut<'_> {
+    fn drop(&mut self) {
+        self.frames.reindex();
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{self, Display};
+
+use crate::{FrameChainEnd, FrameTimingEntry};
+
+/// Timing of a chain of `next` frames, such as a move.
+///
+/// Durations are in ticks (TU). Each frame lasts for `wait + 1` ticks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameTiming {
+    /// Frames in the chain, in the order they are shown.
+    pub entries: Vec<FrameTimingEntry>,
+    /// How the chain ends.
+    pub end: FrameChainEnd,
+}
+
+impl FrameTiming {
+    /// Returns the total number of ticks of the chain.
+    pub fn duration(&self) -> u32 {
+        self.entries.iter().map(|entry| entry.ticks).sum()
+    }
+
+    /// Returns the number of ticks before the first active tick.
+    ///
+    /// This is `None` when no frame has an `itr`.
+    pub fn startup(&self) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|entry| entry.active)
+            .map(|entry| entry.start_tick)
+    }
+
+    /// Returns the number of ticks that have an active `itr`.
+    pub fn active(&self) -> u32 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.active)
+            .map(|entry| entry.ticks)
+            .sum()
+    }
+
+    /// Returns the number of ticks after the last active tick, until the
+    /// chain ends.
+    ///
+    /// When no frame is active, the whole chain is recovery.
+    pub fn recovery(&self) -> u32 {
+        let active_end = self
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| entry.active)
+            .map(|entry| entry.start_tick + entry.ticks)
+            .unwrap_or(0);
+        self.duration() - active_end
+    }
+}
+
+impl Display for FrameTiming {
+    /// Writes a frame data table in Markdown.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "| Frame | Name | Start | Ticks | Active |")?;
+        writeln!(f, "| ----: | :--- | ----: | ----: | :----: |")?;
+        self.entries.iter().try_for_each(|entry| {
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {} |",
+                entry.frame_number,
+                entry.name,
+                entry.start_tick,
+                entry.ticks,
+                if entry.active { "x" } else { "" }
+            )
+        })?;
+
+        writeln!(f)?;
+        writeln!(f, "| Startup | Active | Recovery | Total | End |")?;
+        writeln!(f, "| ------: | -----: | -------: | ----: | :-- |")?;
+        let startup = self
+            .startup()
+            .map(|startup| startup.to_string())
+            .unwrap_or_else(|| String::from("-"));
+        writeln!(
+            f,
+            "| {} | {} | {} | {} | {} |",
+            startup,
+            self.active(),
+            self.recovery(),
+            self.duration(),
+            self.end
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::FrameNumber;
+
+/// Timing of one frame within a `FrameTiming`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameTimingEntry {
+    /// Number of the frame.
+    pub frame_number: FrameNumber,
+    /// Name of the frame.
+    pub name: String,
+    /// Tick that the frame begins on, counting from `0`.
+    pub start_tick: u32,
+    /// Number of ticks the frame is shown for.
+    pub ticks: u32,
+    /// Whether the frame has an `itr` that hits other objects.
+    pub active: bool,
+}
@@ -0,0 +1,33 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Frame, Frames};
+
+/// Mutable slice of the frames in [`Frames`], in file order.
+///
+/// The frames are re-indexed when this is dropped, so frame `number`s may be
+/// changed. See [`Frames::frames_mut`] for how clashing numbers are handled.
+#[derive(Debug)]
+pub struct FramesMut<'f> {
+    /// Frames to re-index when dropped.
+    pub(super) frames: &'f mut Frames,
+}
+
+impl Deref for FramesMut<'_> {
+    type Target = [Frame];
+
+    fn deref(&self) -> &Self::Target {
+        &self.frames.frames
+    }
+}
+
+impl DerefMut for FramesMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.frames.frames
+    }
+}
+
+impl Drop for FramesMut<'_> {
+    fn drop(&mut self) {
+        self.frames.reindex();
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+
+use crate::FrameNumber;
+
+/// How a character reacts to being hit, depending on its accumulated `fall`
+/// points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HitReaction {
+    /// The `itr` has a negative `fall`, so the character does not flinch.
+    None,
+    /// The character has `20` or fewer `fall` points, and switches to
+    /// `injured1` (`220`).
+    Injured1,
+    /// The character has more than `20` `fall` points, and switches to
+    /// `injured2` (`222`).
+    Injured2,
+    /// The character has more than `40` `fall` points, and switches to
+    /// `stunned` (`226`).
+    Stunned,
+    /// The character has more than `60` `fall` points, and falls.
+    Falling,
+}
+
+impl HitReaction {
+    /// `fall` points above which a character switches to `injured2`.
+    pub const FALL_INJURED_2: i32 = 20;
+    /// `fall` points above which a character is stunned.
+    pub const FALL_STUNNED: i32 = 40;
+    /// `fall` points above which a character falls.
+    pub const FALL_FALLING: i32 = 60;
+
+    /// Returns the reaction to a hit.
+    ///
+    /// # Parameters
+    ///
+    /// * `fall`: `fall` of the `itr` that hits.
+    /// * `fall_accumulated`: `fall` points of the character, including this
+    ///   hit.
+    pub fn new(fall: i32, fall_accumulated: i32) -> Self {
+        if fall < 0 {
+            Self::None
+        } else if fall_accumulated > Self::FALL_FALLING {
+            Self::Falling
+        } else if fall_accumulated > Self::FALL_STUNNED {
+            Self::Stunned
+        } else if fall_accumulated > Self::FALL_INJURED_2 {
+            Self::Injured2
+        } else {
+            Self::Injured1
+        }
+    }
+
+    /// Returns the frame that the character switches to, if it is an injured
+    /// frame.
+    pub fn frame_number(self) -> Option<FrameNumber> {
+        match self {
+            Self::Injured1 => Some(FrameNumber(220)),
+            Self::Injured2 => Some(FrameNumber(222)),
+            Self::Stunned => Some(FrameNumber(226)),
+            Self::None | Self::Falling => None,
+        }
+    }
+}
+
+impl Display for HitReaction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Injured1 => write!(f, "injured1"),
+            Self::Injured2 => write!(f, "injured2"),
+            Self::Stunned => write!(f, "stunned"),
+            Self::Falling => write!(f, "falling"),
+        }
+    }
+}
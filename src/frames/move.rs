@@ -0,0 +1,41 @@
+use crate::{Command, FrameNumber, MpCost};
+
+/// Run of consecutive frames with the same name, such as `punch` or
+/// `ice_sword`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    /// Name shared by the frames of the move.
+    pub name: String,
+    /// Frames of the move, in file order.
+    pub frame_numbers: Vec<FrameNumber>,
+    /// Frame that the move is entered from.
+    ///
+    /// This is the first frame that other frames switch to through a `hit_*`
+    /// tag, otherwise through `next`, otherwise the first frame of the move.
+    pub entry: FrameNumber,
+    /// Commands that other frames switch to this move on, e.g. `D>A`.
+    pub inputs: Vec<Command>,
+    /// Total MP and HP paid from the entry frame until the move ends.
+    ///
+    /// The entry frame is paid as if it is reached through a `hit_*` tag, and
+    /// later frames as if reached through `next`.
+    pub cost: MpCost,
+    /// Whether the entry frame costs nothing, but later frames do.
+    ///
+    /// Such moves can be started without MP, and are only interrupted later.
+    pub cost_deferred: bool,
+    /// Estimated HP that the move takes from a target it fully hits.
+    ///
+    /// See [`MoveDamage`](crate::MoveDamage) for how hits are counted.
+    pub damage: i64,
+    /// Number of ticks from the entry frame until the move ends.
+    pub ticks: u32,
+}
+
+impl Move {
+    /// Returns whether this move is entered through a special move command,
+    /// such as `D>A`.
+    pub fn is_special(&self) -> bool {
+        self.inputs.iter().any(|input| input.is_special())
+    }
+}
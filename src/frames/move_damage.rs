@@ -0,0 +1,235 @@
+use std::fmt::{self, Display};
+
+use crate::{Element, FrameNumber, Frames, HitReaction, Itr, ItrKind, MoveHit};
+
+/// Estimated damage of a move on a target, hit by hit.
+///
+/// The estimate assumes that the target stays within reach of every `itr`,
+/// faces the attacker, and is not hit by anything else. Only the first
+/// damaging `itr` of each frame is counted.
+///
+/// An `itr` hits again after `max(arest, vrest)` ticks while the frames that
+/// have it are shown, and the wait carries over to the following frames. An
+/// `itr` without either value hits once per frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveDamage {
+    /// Frame that the move is entered from.
+    pub entry: FrameNumber,
+    /// Hits of the move, in the order they land.
+    pub hits: Vec<MoveHit>,
+}
+
+impl MoveDamage {
+    /// `bdefend` points above which a defending character's defence breaks.
+    pub const B_DEFEND_BREAK: i32 = 30;
+    /// `fall` that is used when an `itr` does not specify one.
+    pub const FALL_DEFAULT: i32 = 20;
+    /// `fall` above which an `itr` hits falling characters.
+    pub const FALL_HITS_FALLING: i32 = 40;
+
+    /// Returns the estimated damage of the move entered at a frame.
+    ///
+    /// Returns `None` if the frame does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the attacking object.
+    /// * `entry`: Frame that the move is entered from.
+    /// * `target`: Frames of the character that is hit, used for the duration
+    ///   of its injured frames.
+    pub fn new(frames: &Frames, entry: FrameNumber, target: &Frames) -> Option<Self> {
+        let frame_timing = frames.timing(entry)?;
+        let mut hits = Vec::<MoveHit>::new();
+        let mut tick_next = 0;
+
+        frame_timing.entries.iter().for_each(|timing_entry| {
+            let frame = match frames.get(timing_entry.frame_number) {
+                Some(frame) => frame,
+                None => return,
+            };
+            let itr_damaging = frame
+                .elements
+                .iter()
+                .filter_map(|element| match element {
+                    Element::Itr(itr) => Some(itr),
+                    _ => None,
+                })
+                .enumerate()
+                .find(|(_, itr)| Self::itr_damaging(itr));
+            let (itr_index, itr) = match itr_damaging {
+                Some(itr_damaging) => itr_damaging,
+                None => return,
+            };
+
+            let rest = itr.a_rest.max(itr.v_rest);
+            let tick_end = timing_entry.start_tick + timing_entry.ticks;
+            let mut tick = timing_entry.start_tick.max(tick_next);
+            while tick < tick_end {
+                let hit = Self::hit(hits.last(), target, tick, frame.number, itr_index, itr);
+                hits.push(hit);
+
+                if rest == 0 {
+                    tick = tick_end;
+                } else {
+                    tick += rest;
+                }
+                tick_next = tick;
+            }
+        });
+
+        Some(MoveDamage { entry, hits })
+    }
+
+    /// Returns the total HP that the move takes.
+    pub fn damage(&self) -> i64 {
+        self.hits.iter().map(|hit| i64::from(hit.injury)).sum()
+    }
+
+    /// Returns whether the move hits at least twice, and every hit lands
+    /// while the target is still reacting to the previous one.
+    pub fn is_combo(&self) -> bool {
+        self.hits.len() >= 2 && self.hits.iter().skip(1).all(|hit| hit.combo)
+    }
+
+    /// Returns the first hit that knocks the target down.
+    pub fn knock_down(&self) -> Option<&MoveHit> {
+        self.hits
+            .iter()
+            .find(|hit| hit.reaction == HitReaction::Falling)
+    }
+
+    /// Returns the first hit that breaks the defence of a defending target.
+    pub fn defend_break(&self) -> Option<&MoveHit> {
+        self.hits
+            .iter()
+            .find(|hit| hit.b_defend_accumulated > Self::B_DEFEND_BREAK)
+    }
+
+    /// Returns whether an `itr` takes HP from what it hits.
+    fn itr_damaging(itr: &Itr) -> bool {
+        matches!(itr.kind, ItrKind::Normal | ItrKind::Falling)
+    }
+
+    /// Returns the hit that an `itr` makes, continuing from the previous hit.
+    ///
+    /// `fall` and `bdefend` points decrease by `1` every tick between hits.
+    fn hit(
+        hit_previous: Option<&MoveHit>,
+        target: &Frames,
+        tick: u32,
+        frame_number: FrameNumber,
+        itr_index: usize,
+        itr: &Itr,
+    ) -> MoveHit {
+        let fall = if itr.fall == 0 {
+            Self::FALL_DEFAULT
+        } else {
+            itr.fall
+        };
+        let (fall_previous, b_defend_previous) = hit_previous
+            .map(|hit_previous| {
+                let elapsed = (tick - hit_previous.tick) as i32;
+                (
+                    (hit_previous.fall_accumulated - elapsed).max(0),
+                    (hit_previous.b_defend_accumulated - elapsed).max(0),
+                )
+            })
+            .unwrap_or((0, 0));
+        let fall_accumulated = fall_previous + fall.max(0);
+        let b_defend_accumulated = b_defend_previous + itr.b_defend.max(0);
+
+        let combo = hit_previous.is_some_and(|hit_previous| match hit_previous.reaction {
+            HitReaction::None => false,
+            HitReaction::Falling => fall > Self::FALL_HITS_FALLING,
+            reaction => reaction
+                .frame_number()
+                .and_then(|frame_number| target.timing(frame_number))
+                .is_some_and(|frame_timing| tick < hit_previous.tick + frame_timing.duration()),
+        });
+
+        MoveHit {
+            tick,
+            frame_number,
+            itr_index,
+            injury: itr.injury,
+            fall,
+            b_defend: itr.b_defend,
+            fall_accumulated,
+            b_defend_accumulated,
+            reaction: HitReaction::new(fall, fall_accumulated),
+            combo,
+        }
+    }
+}
+
+impl Display for MoveDamage {
+    /// Writes a table of the hits in Markdown, followed by a summary.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "| Hit | Tick | Frame | Itr | Injury | Fall | BDefend | Reaction | Combo |"
+        )?;
+        writeln!(
+            f,
+            "| --: | ---: | ----: | --: | -----: | ---: | ------: | :------- | :---: |"
+        )?;
+        self.hits.iter().enumerate().try_for_each(|(index, hit)| {
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {} | {} ({}) | {} ({}) | {} | {} |",
+                index + 1,
+                hit.tick,
+                hit.frame_number,
+                hit.itr_index,
+                hit.injury,
+                hit.fall,
+                hit.fall_accumulated,
+                hit.b_defend,
+                hit.b_defend_accumulated,
+                hit.reaction,
+                if hit.combo { "x" } else { "" }
+            )
+        })?;
+
+        let hit_number = |hit: Option<&MoveHit>| {
+            hit.and_then(|hit| self.hits.iter().position(|h| h == hit))
+                .map(|index| format!("hit {}", index + 1))
+                .unwrap_or_else(|| String::from("-"))
+        };
+
+        writeln!(f)?;
+        writeln!(f, "| Hits | Damage | Combo | Knock down | Defence break |")?;
+        writeln!(f, "| ---: | -----: | :---: | :--------- | :------------ |")?;
+        writeln!(
+            f,
+            "| {} | {} | {} | {} | {} |",
+            self.hits.len(),
+            self.damage(),
+            if self.is_combo() { "yes" } else { "no" },
+            hit_number(self.knock_down()),
+            hit_number(self.defend_break())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::MoveDamage;
+    use crate::{FrameNumber, ObjectData};
+
+    #[test]
+    fn is_combo_requires_at_least_two_hits() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+        let frames = &object_data.frames;
+
+        let punch = MoveDamage::new(frames, FrameNumber(60), frames).unwrap();
+        assert_eq!(1, punch.hits.len());
+        assert!(!punch.is_combo());
+
+        let run_attack = MoveDamage::new(frames, FrameNumber(85), frames).unwrap();
+        assert_eq!(2, run_attack.hits.len());
+        assert!(run_attack.is_combo());
+    }
+}
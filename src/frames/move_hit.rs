@@ -0,0 +1,29 @@
+use crate::{FrameNumber, HitReaction};
+
+/// One hit of a move on a target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveHit {
+    /// Tick that the hit lands on, counting from the move's entry frame.
+    pub tick: u32,
+    /// Frame that the hitting `itr` is in.
+    pub frame_number: FrameNumber,
+    /// Index of the hitting `itr` among the frame's `itr`s.
+    pub itr_index: usize,
+    /// HP that the hit takes.
+    pub injury: i32,
+    /// `fall` points that the hit adds.
+    pub fall: i32,
+    /// `bdefend` points that the hit adds.
+    pub b_defend: i32,
+    /// `fall` points of the target after the hit.
+    pub fall_accumulated: i32,
+    /// `bdefend` points of the target after the hit, if it is defending.
+    pub b_defend_accumulated: i32,
+    /// How the target reacts to the hit.
+    pub reaction: HitReaction,
+    /// Whether the target is still reacting to the previous hit when this hit
+    /// lands.
+    ///
+    /// This is always `false` for the first hit.
+    pub combo: bool,
+}
@@ -0,0 +1,266 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    Command, Frame, FrameNumber, FrameNumberNext, FrameTiming, Frames, Move, MoveDamage, MpCost,
+};
+
+/// Moves of an object, in file order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MoveList(pub Vec<Move>);
+
+impl MoveList {
+    /// Returns the moves of an object, grouping consecutive frames with the
+    /// same name.
+    ///
+    /// A move also ends at a frame whose `next` leaves it, such as `999`,
+    /// `1000`, or a frame outside the move, so that repeated moves with the
+    /// same name are listed separately.
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    pub fn new(frames: &Frames) -> Self {
+        let mut runs = Vec::<Vec<&Frame>>::new();
+        frames
+            .iter()
+            .enumerate()
+            .for_each(|(index, frame)| match runs.last_mut() {
+                Some(run)
+                    if run[0].name == frame.name && !Self::run_leaves(run, &frames[index..]) =>
+                {
+                    run.push(frame)
+                }
+                _ => runs.push(vec![frame]),
+            });
+
+        let moves = runs
+            .into_iter()
+            .map(|run| Self::move_from_run(frames, &run))
+            .collect();
+
+        MoveList(moves)
+    }
+
+    /// Returns whether the last frame of a run switches away from the run.
+    ///
+    /// # Parameters
+    ///
+    /// * `run`: Frames in the run so far.
+    /// * `frames_later`: Frames after the run, in file order.
+    fn run_leaves(run: &[&Frame], frames_later: &[Frame]) -> bool {
+        let frame_last = run[run.len() - 1];
+        if frame_last.next_frame == FrameNumberNext::NONE {
+            return false;
+        }
+
+        match Self::frame_number(frame_last.next_frame) {
+            Some(frame_number) => {
+                let in_run = run.iter().any(|frame| frame.number == frame_number);
+                let in_run_later = frames_later
+                    .iter()
+                    .take_while(|frame| frame.name == frame_last.name)
+                    .any(|frame| frame.number == frame_number);
+                !in_run && !in_run_later
+            }
+            None => true,
+        }
+    }
+
+    fn move_from_run(frames: &Frames, run: &[&Frame]) -> Move {
+        let frame_numbers = run
+            .iter()
+            .map(|frame| frame.number)
+            .collect::<Vec<FrameNumber>>();
+        let frames_outside = || {
+            frames
+                .iter()
+                .filter(|frame| !frame_numbers.contains(&frame.number))
+        };
+
+        // `hit_*` tags from other frames that switch into this run.
+        let mut input_targets = frames_outside()
+            .flat_map(|frame| {
+                IntoIterator::into_iter(Command::ALL)
+                    .map(move |command| (command, command.frame_number_next(frame)))
+            })
+            .filter_map(|(command, frame_number_next)| {
+                Self::frame_number(frame_number_next)
+                    .filter(|frame_number| frame_numbers.contains(frame_number))
+                    .map(|frame_number| (command, frame_number))
+            })
+            .collect::<Vec<(Command, FrameNumber)>>();
+        let next_targets = frames_outside()
+            .filter_map(|frame| Self::frame_number(frame.next_frame))
+            .filter(|frame_number| frame_numbers.contains(frame_number))
+            .collect::<Vec<FrameNumber>>();
+
+        let entry = frame_numbers
+            .iter()
+            .copied()
+            .find(|frame_number| {
+                input_targets
+                    .iter()
+                    .any(|(_, target)| target == frame_number)
+            })
+            .or_else(|| {
+                frame_numbers
+                    .iter()
+                    .copied()
+                    .find(|frame_number| next_targets.contains(frame_number))
+            })
+            .unwrap_or(frame_numbers[0]);
+
+        input_targets.sort_by_key(|(command, _)| *command);
+        let mut inputs = input_targets
+            .into_iter()
+            .map(|(command, _)| command)
+            .collect::<Vec<Command>>();
+        inputs.dedup();
+
+        let frame_timing = frames.timing(entry);
+        let frames_shown = frame_timing
+            .iter()
+            .flat_map(|frame_timing| frame_timing.entries.iter())
+            .filter_map(|entry| frames.get(entry.frame_number))
+            .collect::<Vec<&Frame>>();
+        let (cost_entry, cost_later) = match frames_shown.split_first() {
+            Some((frame_entry, frames_later)) => (
+                frame_entry.mp_cost_hit(),
+                frames_later
+                    .iter()
+                    .map(|frame| frame.mp_cost_next())
+                    .sum::<MpCost>(),
+            ),
+            None => (MpCost::NONE, MpCost::NONE),
+        };
+        let damage = MoveDamage::new(frames, entry, frames)
+            .as_ref()
+            .map(MoveDamage::damage)
+            .unwrap_or_default();
+        let ticks = frame_timing
+            .as_ref()
+            .map(FrameTiming::duration)
+            .unwrap_or_default();
+
+        Move {
+            name: run[0].name.clone(),
+            frame_numbers,
+            entry,
+            inputs,
+            cost: cost_entry + cost_later,
+            cost_deferred: cost_entry.is_none() && !cost_later.is_none(),
+            damage,
+            ticks,
+        }
+    }
+
+    /// Returns the frame that a value switches to, if it is a regular frame.
+    pub(crate) fn frame_number(frame_number_next: FrameNumberNext) -> Option<FrameNumber> {
+        match frame_number_next {
+            FrameNumberNext::NONE | FrameNumberNext::STANDING | FrameNumberNext::DELETE => None,
+            frame_number_next => Some(frame_number_next.abs()),
+        }
+    }
+}
+
+impl Deref for MoveList {
+    type Target = Vec<Move>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MoveList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for MoveList {
+    /// Writes a move list table in Markdown.
+    ///
+    /// MP costs of moves that are only paid after the entry frame are marked
+    /// with `*`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "| Move | Frames | Entry | Input | MP | HP | Damage | Ticks |"
+        )?;
+        writeln!(
+            f,
+            "| :--- | :----- | ----: | :---- | -: | -: | -----: | ----: |"
+        )?;
+        self.0.iter().try_for_each(|r#move| {
+            let frames = match (r#move.frame_numbers.first(), r#move.frame_numbers.last()) {
+                (Some(first), Some(last)) if first != last => format!("{}-{}", first, last),
+                (Some(first), _) => first.to_string(),
+                _ => String::new(),
+            };
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {}{} | {} | {} | {} |",
+                r#move.name,
+                frames,
+                r#move.entry,
+                r#move
+                    .inputs
+                    .iter()
+                    .copied()
+                    .map(Command::notation)
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+                r#move.cost.mp,
+                if r#move.cost_deferred { " *" } else { "" },
+                r#move.cost.hp,
+                r#move.damage,
+                r#move.ticks
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, ops::RangeInclusive};
+
+    use super::MoveList;
+    use crate::{FrameNumber, ObjectData};
+
+    fn frame_numbers(move_list: &MoveList, name: &str) -> Vec<Vec<FrameNumber>> {
+        move_list
+            .iter()
+            .filter(|r#move| r#move.name == name)
+            .map(|r#move| r#move.frame_numbers.clone())
+            .collect()
+    }
+
+    #[test]
+    fn splits_frames_with_the_same_name_when_next_leaves_the_run() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+        let move_list = MoveList::new(&object_data.frames);
+
+        let punches = frame_numbers(&move_list, "punch");
+        let numbers = |range: RangeInclusive<usize>| range.map(FrameNumber).collect::<Vec<_>>();
+        assert_eq!(vec![numbers(60..=64), numbers(65..=69)], punches);
+    }
+
+    #[test]
+    fn keeps_frames_together_when_next_stays_in_the_run() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 spin\n  pic: 0  wait: 1  next: 2\n<frame_end>\n\
+            <frame> 1 spin\n  pic: 0  wait: 1  next: 0\n<frame_end>\n\
+            <frame> 2 spin\n  pic: 0  wait: 1  next: 1\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+        let move_list = MoveList::new(&object_data.frames);
+
+        let spins = frame_numbers(&move_list, "spin");
+        assert_eq!(
+            vec![vec![FrameNumber(0), FrameNumber(1), FrameNumber(2)]],
+            spins
+        );
+    }
+}
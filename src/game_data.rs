@@ -0,0 +1,62 @@
+use std::collections::{btree_map::Values, BTreeMap};
+
+use crate::{GameObject, ObjectId};
+
+pub use self::{
+    balance_report::BalanceReport, balance_report_entry::BalanceReportEntry,
+    mp_cost_report::MpCostReport, mp_cost_report_entry::MpCostReportEntry,
+};
+
+mod balance_report;
+mod balance_report_entry;
+mod mp_cost_report;
+mod mp_cost_report_entry;
+
+/// Set of objects that make up a game, indexed by their `data.txt` ID.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameData {
+    /// Objects keyed by their ID.
+    objects: BTreeMap<ObjectId, GameObject>,
+}
+
+impl GameData {
+    /// Returns an empty `GameData`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts an object, returning the object previously stored with the
+    /// same ID.
+    pub fn insert(&mut self, game_object: GameObject) -> Option<GameObject> {
+        self.objects.insert(game_object.id, game_object)
+    }
+
+    /// Returns the object with the given ID.
+    pub fn get(&self, object_id: ObjectId) -> Option<&GameObject> {
+        self.objects.get(&object_id)
+    }
+
+    /// Returns an iterator over the objects in ID order.
+    pub fn iter(&self) -> Values<'_, ObjectId, GameObject> {
+        self.objects.values()
+    }
+
+    /// Returns the number of objects.
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Returns `true` if there are no objects.
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+}
+
+impl<'g> IntoIterator for &'g GameData {
+    type Item = &'g GameObject;
+    type IntoIter = Values<'g, ObjectId, GameObject>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
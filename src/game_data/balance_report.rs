@@ -0,0 +1,191 @@
+use std::fmt::{self, Display};
+
+use crate::{BalanceReportEntry, GameData, ObjectType};
+
+/// Side by side comparison of the characters in a game.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BalanceReport {
+    /// Stats of each character, ordered by character ID.
+    pub entries: Vec<BalanceReportEntry>,
+}
+
+impl BalanceReport {
+    /// Column headings, and whether each column is text rather than a number.
+    const COLUMNS: &'static [(&'static str, bool)] = &[
+        ("ID", false),
+        ("Character", true),
+        ("Walk", false),
+        ("Walk Z", false),
+        ("Run", false),
+        ("Run Z", false),
+        ("Jump height", false),
+        ("Jump distance", false),
+        ("Jump distance Z", false),
+        ("Dash height", false),
+        ("Dash distance", false),
+        ("Dash distance Z", false),
+        ("Fastest attack", true),
+        ("Startup", false),
+        ("Strongest hit", true),
+        ("Hit damage", false),
+        ("Standing bdy area", false),
+        ("Special moves", false),
+        ("Total MP", false),
+        ("Max MP", false),
+        ("Total HP", false),
+    ];
+
+    /// Returns the stats of the characters in the game.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_data`: Objects of the game.
+    pub fn new(game_data: &GameData) -> Self {
+        let entries = game_data
+            .iter()
+            .filter(|game_object| game_object.object_type == ObjectType::Character)
+            .map(BalanceReportEntry::new)
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns the report as comma separated values, with a heading row.
+    ///
+    /// Values that contain commas or quotes are quoted.
+    pub fn to_csv(&self) -> String {
+        let line = |values: Vec<String>| {
+            let values = values
+                .into_iter()
+                .map(|value| {
+                    if value.contains(&[',', '"', '\n'][..]) {
+                        format!("\"{}\"", value.replace('"', "\"\""))
+                    } else {
+                        value
+                    }
+                })
+                .collect::<Vec<String>>();
+            format!("{}\n", values.join(","))
+        };
+
+        let headings = Self::COLUMNS
+            .iter()
+            .map(|(heading, _)| heading.to_string())
+            .collect::<Vec<String>>();
+        std::iter::once(headings)
+            .chain(self.entries.iter().map(Self::row))
+            .map(line)
+            .collect()
+    }
+
+    /// Returns the values of an entry, in the order of [`Self::COLUMNS`].
+    ///
+    /// Missing values are empty.
+    fn row(entry: &BalanceReportEntry) -> Vec<String> {
+        let header = &entry.header;
+        let (startup_name, startup_ticks) = match &entry.startup_fastest {
+            Some((name, ticks)) => (name.clone(), ticks.to_string()),
+            None => (String::new(), String::new()),
+        };
+        let (hit_name, hit_injury) = match &entry.hit_damage_max {
+            Some((name, injury)) => (name.clone(), injury.to_string()),
+            None => (String::new(), String::new()),
+        };
+
+        vec![
+            entry.object_id.to_string(),
+            header.name.clone(),
+            header.walking_speed.to_string(),
+            header.walking_speed_z.to_string(),
+            header.running_speed.to_string(),
+            header.running_speed_z.to_string(),
+            header.jump_height.to_string(),
+            header.jump_distance.to_string(),
+            header.jump_distance_z.to_string(),
+            header.dash_height.to_string(),
+            header.dash_distance.to_string(),
+            header.dash_distance_z.to_string(),
+            startup_name,
+            startup_ticks,
+            hit_name,
+            hit_injury,
+            entry.standing_bdy_area.to_string(),
+            entry.special_moves.to_string(),
+            entry.special_mp_total.to_string(),
+            entry.special_mp_max.to_string(),
+            entry.special_hp_total.to_string(),
+        ]
+    }
+}
+
+impl Display for BalanceReport {
+    /// Writes the report as a Markdown table.
+    ///
+    /// Missing values are written as `-`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let headings = Self::COLUMNS
+            .iter()
+            .map(|(heading, _)| *heading)
+            .collect::<Vec<&str>>();
+        let alignments = Self::COLUMNS
+            .iter()
+            .map(|(_, text)| if *text { ":--" } else { "--:" })
+            .collect::<Vec<&str>>();
+        writeln!(f, "| {} |", headings.join(" | "))?;
+        writeln!(f, "| {} |", alignments.join(" | "))?;
+
+        self.entries.iter().try_for_each(|entry| {
+            let values = Self::row(entry)
+                .into_iter()
+                .map(|value| {
+                    if value.is_empty() {
+                        String::from("-")
+                    } else {
+                        value
+                    }
+                })
+                .collect::<Vec<String>>();
+            writeln!(f, "| {} |", values.join(" | "))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::BalanceReport;
+    use crate::{GameData, GameObject, ObjectData, ObjectId, ObjectType};
+
+    #[test]
+    fn reports_characters_only_with_csv_row_per_character() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+        let mut game_data = GameData::new();
+        [
+            (ObjectId(11), ObjectType::Character),
+            (ObjectId(100), ObjectType::LightWeapon),
+        ]
+        .iter()
+        .for_each(|(id, object_type)| {
+            game_data.insert(GameObject {
+                id: *id,
+                object_type: *object_type,
+                object_data: object_data.clone(),
+            });
+        });
+
+        let balance_report = BalanceReport::new(&game_data);
+        assert_eq!(1, balance_report.entries.len());
+
+        let entry = &balance_report.entries[0];
+        assert_eq!(ObjectId(11), entry.object_id);
+        assert_eq!(Some((String::from("punch"), 2)), entry.startup_fastest);
+        assert_eq!(7, entry.special_moves);
+
+        let csv = balance_report.to_csv();
+        let lines = csv.lines().collect::<Vec<&str>>();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with("ID,Character,Walk,"));
+        assert!(lines[1].starts_with("11,Frozen,"));
+    }
+}
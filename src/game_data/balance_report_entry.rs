@@ -0,0 +1,109 @@
+use crate::{Element, Frame, GameObject, Header, Move, ObjectId, State};
+
+/// Stats of one character in a `BalanceReport`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BalanceReportEntry {
+    /// ID of the character in `data.txt`.
+    pub object_id: ObjectId,
+    /// Header of the character, with its name and movement stats.
+    pub header: Header,
+    /// Name and startup ticks of the attack that hits soonest.
+    pub startup_fastest: Option<(String, u32)>,
+    /// Name and `injury` of the attack with the most damaging single hit.
+    pub hit_damage_max: Option<(String, i32)>,
+    /// Total area of the `bdy`s in all standing frames.
+    pub standing_bdy_area: u64,
+    /// Number of special moves.
+    pub special_moves: usize,
+    /// Total MP of the special moves.
+    pub special_mp_total: i64,
+    /// MP of the most expensive special move.
+    pub special_mp_max: i64,
+    /// Total HP of the special moves.
+    pub special_hp_total: i64,
+}
+
+impl BalanceReportEntry {
+    /// Returns the stats of a character.
+    ///
+    /// Attacks are moves that are entered through a command or start with an
+    /// attacking frame, and that hit a copy of the same character.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_object`: The character.
+    pub fn new(game_object: &GameObject) -> Self {
+        let object_data = &game_object.object_data;
+        let frames = &object_data.frames;
+        let moves = frames.moves();
+
+        let attacks = moves
+            .iter()
+            .filter(|r#move| {
+                !r#move.inputs.is_empty()
+                    || frames
+                        .get(r#move.entry)
+                        .is_some_and(|frame| frame.state == State::Attacking)
+            })
+            .filter_map(|r#move| {
+                frames
+                    .damage(r#move.entry, frames)
+                    .filter(|move_damage| !move_damage.hits.is_empty())
+                    .map(|move_damage| (r#move, move_damage))
+            })
+            .collect::<Vec<_>>();
+
+        let startup_fastest = attacks
+            .iter()
+            .map(|(r#move, move_damage)| (r#move.name.clone(), move_damage.hits[0].tick))
+            .min_by_key(|(_, tick)| *tick);
+        let hit_damage_max = attacks
+            .iter()
+            .flat_map(|(r#move, move_damage)| {
+                move_damage
+                    .hits
+                    .iter()
+                    .map(move |hit| (r#move.name.clone(), hit.injury))
+            })
+            .max_by_key(|(_, injury)| *injury);
+
+        let standing_bdy_area = frames
+            .iter()
+            .filter(|frame| frame.state == State::Standing)
+            .map(Self::frame_bdy_area)
+            .sum();
+
+        let special_moves = moves
+            .iter()
+            .filter(|r#move| r#move.is_special())
+            .collect::<Vec<&Move>>();
+
+        BalanceReportEntry {
+            object_id: game_object.id,
+            header: object_data.header.clone(),
+            startup_fastest,
+            hit_damage_max,
+            standing_bdy_area,
+            special_moves: special_moves.len(),
+            special_mp_total: special_moves.iter().map(|r#move| r#move.cost.mp).sum(),
+            special_mp_max: special_moves
+                .iter()
+                .map(|r#move| r#move.cost.mp)
+                .max()
+                .unwrap_or(0),
+            special_hp_total: special_moves.iter().map(|r#move| r#move.cost.hp).sum(),
+        }
+    }
+
+    /// Returns the total area of a frame's `bdy`s.
+    fn frame_bdy_area(frame: &Frame) -> u64 {
+        frame
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                Element::Bdy(bdy) => Some(u64::from(bdy.w) * u64::from(bdy.h)),
+                _ => None,
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::{self, Display};
+
+use crate::{Command, GameData, MpCost, MpCostReportEntry, ObjectType};
+
+/// MP and HP costs of every character's special moves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MpCostReport {
+    /// Special moves, ordered by character ID, then by entry frame.
+    pub entries: Vec<MpCostReportEntry>,
+}
+
+impl MpCostReport {
+    /// Returns the costs of the special moves of the characters in the game.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_data`: Objects of the game.
+    pub fn new(game_data: &GameData) -> Self {
+        let entries = game_data
+            .iter()
+            .filter(|game_object| game_object.object_type == ObjectType::Character)
+            .flat_map(|game_object| {
+                let mut special_moves = game_object
+                    .object_data
+                    .frames
+                    .moves()
+                    .0
+                    .into_iter()
+                    .filter(|r#move| r#move.is_special())
+                    .collect::<Vec<_>>();
+                special_moves.sort_by_key(|r#move| r#move.entry);
+
+                special_moves
+                    .into_iter()
+                    .map(move |special_move| MpCostReportEntry {
+                        object_id: game_object.id,
+                        object_name: game_object.object_data.header.name.clone(),
+                        special_move,
+                    })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns the entries that are paid for only after their entry frame.
+    pub fn costs_deferred(&self) -> impl Iterator<Item = &MpCostReportEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.special_move.cost_deferred)
+    }
+}
+
+impl Display for MpCostReport {
+    /// Writes a table of each special move, followed by a table of totals per
+    /// character, in Markdown.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "| ID | Character | Move | Entry | Input | MP | HP | Deferred |"
+        )?;
+        writeln!(
+            f,
+            "| -: | :-------- | :--- | ----: | :---- | -: | -: | :------: |"
+        )?;
+        self.entries.iter().try_for_each(|entry| {
+            let special_move = &entry.special_move;
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {} | {} | {} | {} |",
+                entry.object_id,
+                entry.object_name,
+                special_move.name,
+                special_move.entry,
+                special_move
+                    .inputs
+                    .iter()
+                    .copied()
+                    .map(Command::notation)
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+                special_move.cost.mp,
+                special_move.cost.hp,
+                if special_move.cost_deferred { "x" } else { "" }
+            )
+        })?;
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "| ID | Character | Special moves | Total MP | Max MP | Total HP |"
+        )?;
+        writeln!(
+            f,
+            "| -: | :-------- | ------------: | -------: | -----: | -------: |"
+        )?;
+        let mut entries = self.entries.iter().peekable();
+        while let Some(entry_first) = entries.next() {
+            let mut count = 1;
+            let mut total = entry_first.special_move.cost;
+            let mut mp_max = entry_first.special_move.cost.mp;
+            while let Some(entry) =
+                entries.next_if(|entry| entry.object_id == entry_first.object_id)
+            {
+                count += 1;
+                total += entry.special_move.cost;
+                mp_max = mp_max.max(entry.special_move.cost.mp);
+            }
+
+            let MpCost { mp, hp } = total;
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {} | {} |",
+                entry_first.object_id, entry_first.object_name, count, mp, mp_max, hp
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::MpCostReport;
+    use crate::{FrameNumber, GameData, GameObject, MpCost, ObjectData, ObjectId, ObjectType};
+
+    #[test]
+    fn special_moves_pay_on_entry_or_later() {
+        let object_data_str = "<bmp_begin>\nname: Tester\n<bmp_end>\n\
+            <frame> 0 standing\n  pic: 0  state: 0  wait: 1  next: 0  hit_Fa: 10  hit_Uj: 20\n<frame_end>\n\
+            <frame> 10 ball\n  pic: 0  state: 3  wait: 1  next: 11  mp: 1100\n<frame_end>\n\
+            <frame> 11 ball\n  pic: 0  state: 3  wait: 1  next: 999\n<frame_end>\n\
+            <frame> 20 heal\n  pic: 0  state: 3  wait: 1  next: 21\n<frame_end>\n\
+            <frame> 21 heal\n  pic: 0  state: 3  wait: 1  next: 999  mp: -50\n<frame_end>\n";
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: ObjectId(1),
+            object_type: ObjectType::Character,
+            object_data: ObjectData::try_from(object_data_str).unwrap(),
+        });
+
+        let mp_cost_report = MpCostReport::new(&game_data);
+
+        let costs = mp_cost_report
+            .entries
+            .iter()
+            .map(|entry| {
+                let special_move = &entry.special_move;
+                (
+                    special_move.entry,
+                    special_move.cost,
+                    special_move.cost_deferred,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (FrameNumber(10), MpCost { mp: 100, hp: 10 }, false),
+                (FrameNumber(20), MpCost { mp: 50, hp: 0 }, true),
+            ],
+            costs
+        );
+        assert_eq!(1, mp_cost_report.costs_deferred().count());
+    }
+}
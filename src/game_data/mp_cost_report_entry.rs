@@ -0,0 +1,12 @@
+use crate::{Move, ObjectId};
+
+/// Special move of one character in an `MpCostReport`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MpCostReportEntry {
+    /// ID of the character in `data.txt`.
+    pub object_id: ObjectId,
+    /// Name of the character, from its header.
+    pub object_name: String,
+    /// The special move, with its cost.
+    pub special_move: Move,
+}
@@ -0,0 +1,12 @@
+use crate::{ObjectData, ObjectId, ObjectType};
+
+/// Object data together with its `data.txt` entry information.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameObject {
+    /// ID of the object in `data.txt`.
+    pub id: ObjectId,
+    /// Type of the object in `data.txt`.
+    pub object_type: ObjectType,
+    /// Parsed object data.
+    pub object_data: ObjectData,
+}
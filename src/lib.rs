@@ -1,6 +1,8 @@
 //! Parses Little Fighter 2 (LF2) data files into an in-memory model.
 
 pub use crate::{
+    command::{Command, CommandParseError},
+    data_txt::{DataTxt, DataTxtEntry},
     element::{
         BPoint, Bdy, BdyKind, BdyKindParseError, CPoint, CPointKind, CPointKindParseError, Effect,
         EffectParseError, Element, Itr, ItrKind, ItrKindParseError, OPoint, OPointFacing,
@@ -8,25 +10,64 @@ pub use crate::{
         WPointKindParseError,
     },
     error::Error,
-    frame::{Frame, FrameNumber, FrameNumberNext, Pic, State, StateParseError, Wait},
-    frames::Frames,
+    frame::{Frame, FrameNumber, FrameNumberNext, MpCost, Pic, State, StateParseError, Wait},
+    frames::{
+        CommandList, CommandTransition, CommandTransitions, FrameChainEnd, FrameMut, FrameTiming,
+        FrameTimingEntry, Frames, FramesMut, HitReaction, Move, MoveDamage, MoveHit, MoveList,
+    },
+    game_data::{BalanceReport, BalanceReportEntry, GameData, MpCostReport, MpCostReportEntry},
+    game_object::GameObject,
     header::Header,
+    lint::{
+        Diagnostic, ElementBounds, FrameLoop, FrameLoopKind, FrameLoops, FrameReachability,
+        ItrFields, PicUsage, Severity, StateRule, StateRuleFn, StateRules,
+    },
     object_data::ObjectData,
+    object_data_diff::{FieldChange, FrameDiff, ObjectDataDiff},
     object_data_parser::{ObjectDataParser, Rule, SubRuleFn, SubRuleWrapper},
     object_id::ObjectId,
+    object_type::{ObjectType, ObjectTypeParseError},
+    parse_options::{
+        FrameLimit, FrameLimitParseError, FrameNumberDuplicateMode, ParseOptions, Strictness,
+    },
+    parse_output::{FrameSpan, ParseOutput},
+    render::{
+        Animation, AnimationFormat, AnimationFormatParseError, AnimationFrame, FrameRenderer,
+        RenderError,
+    },
+    sim::{
+        Facing, Grab, HitBox, Input, InstanceId, ObjectInstance, Position, Replay, ReplayError,
+        ReplayInput, ReplayParseError, ReplayRecorder, ReplaySpawn, ReplayTick, SimError,
+        Simulation, Velocity, WeaponHold,
+    },
+    sprite::{SpriteError, SpriteImage, SpriteSheet, Sprites},
     sprite_file::SpriteFile,
     weapon_strength::WeaponStrength,
     weapon_strength_index::WeaponStrengthIndex,
+    weapon_strength_list::WeaponStrengthList,
 };
 
+mod command;
+mod data_txt;
 mod element;
 mod error;
 mod frame;
 mod frames;
+mod game_data;
+mod game_object;
 mod header;
+mod lint;
 mod object_data;
+mod object_data_diff;
 mod object_data_parser;
 mod object_id;
+mod object_type;
+mod parse_options;
+mod parse_output;
+mod render;
+mod sim;
+mod sprite;
 mod sprite_file;
 mod weapon_strength;
 mod weapon_strength_index;
+mod weapon_strength_list;
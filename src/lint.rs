@@ -0,0 +1,27 @@
+//! Checks for mistakes in object data that parse successfully.
+
+pub use self::{
+    diagnostic::Diagnostic,
+    element_bounds::ElementBounds,
+    frame_loop::FrameLoop,
+    frame_loop_kind::FrameLoopKind,
+    frame_loops::FrameLoops,
+    frame_reachability::FrameReachability,
+    itr_fields::ItrFields,
+    pic_usage::PicUsage,
+    severity::Severity,
+    state_rule::{StateRule, StateRuleFn},
+    state_rules::StateRules,
+};
+
+mod diagnostic;
+mod element_bounds;
+mod frame_loop;
+mod frame_loop_kind;
+mod frame_loops;
+mod frame_reachability;
+mod itr_fields;
+mod pic_usage;
+mod severity;
+mod state_rule;
+mod state_rules;
@@ -0,0 +1,43 @@
+use std::fmt::{self, Display};
+
+use crate::{FrameNumber, Severity};
+
+/// Problem found when checking object data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Identifier of the check that produced this diagnostic.
+    pub code: &'static str,
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// Frame that the problem is in, if it is specific to one frame.
+    pub frame_number: Option<FrameNumber>,
+    /// Description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Returns a new `Diagnostic`.
+    pub fn new(
+        code: &'static str,
+        severity: Severity,
+        frame_number: Option<FrameNumber>,
+        message: String,
+    ) -> Self {
+        Self {
+            code,
+            severity,
+            frame_number,
+            message,
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]", self.severity, self.code)?;
+        if let Some(frame_number) = self.frame_number {
+            write!(f, " frame {}", frame_number)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
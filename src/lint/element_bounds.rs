@@ -0,0 +1,144 @@
+use crate::{Diagnostic, Element, Frame, Frames, Header, Severity};
+
+/// Checks that frame elements and centers lie within the frame's sprite cell.
+#[derive(Debug)]
+pub struct ElementBounds;
+
+impl ElementBounds {
+    /// A box or point lies entirely outside the sprite cell.
+    pub const ELEMENT_OUTSIDE_CELL: &'static str = "element-outside-cell";
+    /// A `bdy` or `itr` has no width or height.
+    pub const ELEMENT_ZERO_AREA: &'static str = "element-zero-area";
+    /// The frame's `centerx` / `centery` is outside the sprite cell.
+    pub const CENTER_OUTSIDE_CELL: &'static str = "center-outside-cell";
+
+    /// Returns diagnostics for elements and centers outside the sprite cell of
+    /// each frame's pic, and for `bdy` and `itr` boxes with no area.
+    ///
+    /// Frames whose pic is not in any sprite sheet are only checked for empty
+    /// boxes.
+    pub fn check(header: &Header, frames: &Frames) -> Vec<Diagnostic> {
+        frames
+            .iter()
+            .flat_map(|frame| {
+                let cell = header
+                    .sprite_files
+                    .iter()
+                    .find(|sprite_file| sprite_file.pic_range().contains(&frame.pic.abs()))
+                    .map(|sprite_file| (i64::from(sprite_file.w()), i64::from(sprite_file.h())));
+
+                let mut diagnostics = Vec::new();
+                if let Some((cell_w, cell_h)) = cell {
+                    if !Self::point_inside(frame.center_x, frame.center_y, cell_w, cell_h) {
+                        diagnostics.push(Diagnostic::new(
+                            Self::CENTER_OUTSIDE_CELL,
+                            Severity::Warning,
+                            Some(frame.number),
+                            format!(
+                                "`{}` has its center ({}, {}) outside its {}x{} sprite cell.",
+                                frame.name, frame.center_x, frame.center_y, cell_w, cell_h
+                            ),
+                        ));
+                    }
+                }
+                frame.elements.iter().for_each(|element| {
+                    if let Some(diagnostic) = Self::element_check(frame, element, cell) {
+                        diagnostics.push(diagnostic);
+                    }
+                });
+
+                diagnostics
+            })
+            .collect()
+    }
+
+    fn element_check(
+        frame: &Frame,
+        element: &Element,
+        cell: Option<(i64, i64)>,
+    ) -> Option<Diagnostic> {
+        let (tag, x, y, size) = match element {
+            Element::Bdy(bdy) => ("bdy", bdy.x, bdy.y, Some((bdy.w, bdy.h))),
+            Element::Itr(itr) => ("itr", itr.x, itr.y, Some((itr.w, itr.h))),
+            Element::BPoint(b_point) => ("bpoint", b_point.x, b_point.y, None),
+            Element::CPoint(c_point) => ("cpoint", c_point.x, c_point.y, None),
+            Element::OPoint(o_point) => ("opoint", o_point.x, o_point.y, None),
+            Element::WPoint(w_point) => ("wpoint", w_point.x, w_point.y, None),
+        };
+        let (x, y) = (i64::from(x), i64::from(y));
+
+        match (size, cell) {
+            (Some((w, h)), _) if w == 0 || h == 0 => Some(Diagnostic::new(
+                Self::ELEMENT_ZERO_AREA,
+                Severity::Warning,
+                Some(frame.number),
+                format!(
+                    "`{}`'s `{}` at ({}, {}) has no area: `w: {}  h: {}`.",
+                    frame.name, tag, x, y, w, h
+                ),
+            )),
+            (Some((w, h)), Some((cell_w, cell_h))) => {
+                let (w, h) = (i64::from(w), i64::from(h));
+                let overlaps = x < cell_w && x + w > 0 && y < cell_h && y + h > 0;
+                if overlaps {
+                    return None;
+                }
+                Some(Diagnostic::new(
+                    Self::ELEMENT_OUTSIDE_CELL,
+                    Severity::Warning,
+                    Some(frame.number),
+                    format!(
+                        "`{}`'s `{}` at ({}, {}) sized {}x{} is outside its {}x{} sprite cell.",
+                        frame.name, tag, x, y, w, h, cell_w, cell_h
+                    ),
+                ))
+            }
+            (None, Some((cell_w, cell_h))) if !Self::point_inside(x, y, cell_w, cell_h) => {
+                Some(Diagnostic::new(
+                    Self::ELEMENT_OUTSIDE_CELL,
+                    Severity::Warning,
+                    Some(frame.number),
+                    format!(
+                        "`{}`'s `{}` at ({}, {}) is outside its {}x{} sprite cell.",
+                        frame.name, tag, x, y, cell_w, cell_h
+                    ),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether a point is within a cell, allowing up to 2 pixels past
+    /// its right and bottom edges.
+    ///
+    /// Cells are followed by a 1 pixel gutter, and centers are commonly placed
+    /// 1 pixel below that, at the character's feet, e.g. `centery: 80` in a
+    /// cell with `h: 79`.
+    fn point_inside(x: i64, y: i64, cell_w: i64, cell_h: i64) -> bool {
+        (0..=cell_w + 1).contains(&x) && (0..=cell_h + 1).contains(&y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::ElementBounds;
+    use crate::{FrameNumber, ObjectData};
+
+    #[test]
+    fn centers_may_be_up_to_two_pixels_past_the_cell() {
+        let object_data_str = "<bmp_begin>\n\
+            file(0-9): sprite\\test.bmp  w: 79  h: 79  row: 10  col: 1\n\
+            <bmp_end>\n\
+            <frame> 0 standing\n  pic: 0  wait: 1  next: 0  centerx: 80  centery: 80\n<frame_end>\n\
+            <frame> 1 standing\n  pic: 0  wait: 1  next: 0  centerx: 39  centery: 81\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let diagnostics = ElementBounds::check(&object_data.header, &object_data.frames);
+
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(ElementBounds::CENTER_OUTSIDE_CELL, diagnostics[0].code);
+        assert_eq!(Some(FrameNumber(1)), diagnostics[0].frame_number);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{FrameLoopKind, FrameNumber};
+
+/// Frames whose `next` values form a loop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameLoop {
+    /// Frames in the loop, in `next` order starting from the lowest number.
+    pub frame_numbers: Vec<FrameNumber>,
+    /// Whether the loop is intentional or can soft lock the object.
+    pub kind: FrameLoopKind,
+}
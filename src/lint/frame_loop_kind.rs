@@ -0,0 +1,24 @@
+use std::fmt::{self, Display};
+
+/// Classification of a loop of `next` frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FrameLoopKind {
+    /// Intentional loop in a neutral state, such as standing, walking, or a
+    /// ball in flight.
+    Idle,
+    /// Loop that can be left through a `hit_*` input, or that the game exits
+    /// on its own, such as when landing or being released.
+    Escapable,
+    /// Loop that cannot be left, which soft locks the object.
+    Trap,
+}
+
+impl Display for FrameLoopKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Idle => write!(f, "idle"),
+            Self::Escapable => write!(f, "escapable"),
+            Self::Trap => write!(f, "trap"),
+        }
+    }
+}
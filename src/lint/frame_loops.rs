@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    Diagnostic, Frame, FrameLoop, FrameLoopKind, FrameNumber, FrameNumberNext, FrameReachability,
+    Frames, ObjectType, Severity, State,
+};
+
+/// Finds loops in the graph of frames' `next` values.
+///
+/// Each frame has one `next`, so the strongly connected components of the
+/// graph with more than one frame, or a frame that stays on itself with `next:
+/// 0`, are exactly its cycles. `next: 999` and `next: 1000` leave the graph.
+#[derive(Debug)]
+pub struct FrameLoops;
+
+impl FrameLoops {
+    /// A loop of frames cannot be left.
+    pub const FRAME_LOOP_TRAP: &'static str = "frame-loop-trap";
+
+    /// Returns the loops in the `next` graph, ordered by their lowest frame
+    /// number.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_type`: Type of the object, which determines the frames that
+    ///   the game switches the object in and out of on its own.
+    /// * `frames`: Frames of the object.
+    pub fn find(object_type: ObjectType, frames: &Frames) -> Vec<FrameLoop> {
+        let entries_engine = FrameReachability::entries_engine(object_type);
+        let mut visited = BTreeMap::<FrameNumber, usize>::new();
+        let mut frame_loops = Vec::new();
+
+        frames.iter().enumerate().for_each(|(walk, frame)| {
+            let mut path = Vec::<&Frame>::new();
+            let mut frame = Some(frame);
+            while let Some(frame_current) = frame {
+                match visited.get(&frame_current.number) {
+                    // Reached a frame from an earlier walk, whose loops are already found.
+                    Some(walk_visited) if *walk_visited != walk => break,
+                    Some(_) => {
+                        let loop_start = path
+                            .iter()
+                            .position(|frame| frame.number == frame_current.number)
+                            .unwrap_or(0);
+                        frame_loops.push(Self::frame_loop(&entries_engine, &path[loop_start..]));
+                        break;
+                    }
+                    None => {
+                        visited.insert(frame_current.number, walk);
+                        path.push(frame_current);
+                    }
+                }
+                frame = Self::next(frames, frame_current);
+            }
+        });
+
+        frame_loops.sort_by_key(|frame_loop: &FrameLoop| frame_loop.frame_numbers[0]);
+        frame_loops
+    }
+
+    /// Returns diagnostics for loops that cannot be left.
+    ///
+    /// Use [`FrameLoops::find`] to list idle and escapable loops as well.
+    pub fn check(object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        Self::find(object_type, frames)
+            .into_iter()
+            .filter(|frame_loop| frame_loop.kind == FrameLoopKind::Trap)
+            .map(|frame_loop| {
+                let frame_numbers = frame_loop
+                    .frame_numbers
+                    .iter()
+                    .map(FrameNumber::to_string)
+                    .collect::<Vec<String>>()
+                    .join(" -> ");
+                Diagnostic::new(
+                    Self::FRAME_LOOP_TRAP,
+                    Severity::Warning,
+                    Some(frame_loop.frame_numbers[0]),
+                    format!(
+                        "Frames {} -> {} loop with no `hit_*` or state that leaves the loop.",
+                        frame_numbers, frame_loop.frame_numbers[0]
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the frame that a frame goes to after its `wait`.
+    fn next<'f>(frames: &'f Frames, frame: &'f Frame) -> Option<&'f Frame> {
+        match frame.next_frame {
+            FrameNumberNext::NONE => Some(frame),
+            FrameNumberNext::STANDING | FrameNumberNext::DELETE => None,
+            next => frames.get(next.abs()),
+        }
+    }
+
+    fn frame_loop(entries_engine: &[FrameNumber], frames: &[&Frame]) -> FrameLoop {
+        let hold = match frames {
+            [frame] if frame.next_frame == FrameNumberNext::NONE => Some(frame),
+            _ => None,
+        };
+        // A ball that stays on one frame with `next: 0` never animates or
+        // reaches its hit frames on its own, unlike a looping flight.
+        let ball_stuck = hold.is_some_and(|frame| Self::state_ball(frame.state));
+        // Frames that the game enters on its own, such as dash attack or tired,
+        // are also left by the game, so staying on them with `next: 0` is
+        // intended.
+        let engine_hold =
+            !ball_stuck && hold.is_some_and(|frame| entries_engine.contains(&frame.number));
+        let kind = if !ball_stuck && frames.iter().any(|frame| Self::state_idle(frame.state)) {
+            FrameLoopKind::Idle
+        } else if engine_hold
+            || frames
+                .iter()
+                .any(|frame| Self::hit_escape(frame) || Self::state_exits(frame.state))
+        {
+            FrameLoopKind::Escapable
+        } else {
+            FrameLoopKind::Trap
+        };
+
+        let start = frames
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, frame)| frame.number)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        let frame_numbers = frames[start..]
+            .iter()
+            .chain(frames[..start].iter())
+            .map(|frame| frame.number)
+            .collect();
+
+        FrameLoop {
+            frame_numbers,
+            kind,
+        }
+    }
+
+    /// Returns whether a frame has a `hit_*` input that leaves it.
+    fn hit_escape(frame: &Frame) -> bool {
+        [
+            frame.hit_a,
+            frame.hit_d,
+            frame.hit_da,
+            frame.hit_dj,
+            frame.hit_fa,
+            frame.hit_fj,
+            frame.hit_j,
+            frame.hit_ja,
+            frame.hit_ua,
+            frame.hit_uj,
+        ]
+        .iter()
+        .any(|hit| *hit != FrameNumberNext::NONE)
+    }
+
+    /// Returns whether a state is neutral, where looping is intended.
+    fn state_idle(state: State) -> bool {
+        matches!(
+            state,
+            State::Standing
+                | State::Walking
+                | State::Running
+                | State::LightWeaponInSky
+                | State::LightWeaponInHand
+                | State::LightWeaponBeingThrown
+                | State::LightWeaponJustOnGround
+                | State::LightWeaponOnGround
+                | State::HeavyWeaponInSky
+                | State::HeavyWeaponInHand
+                | State::HeavyWeaponOnGround
+        ) || Self::state_ball(state)
+    }
+
+    /// Returns whether a state is a flying ball's.
+    fn state_ball(state: State) -> bool {
+        matches!(
+            state,
+            State::BallFlying | State::BallFlyingNoShadow | State::BallFlyingPiercing
+        )
+    }
+
+    /// Returns whether the game switches frames on its own for a state, such as
+    /// when landing, being released, or running out of a drink.
+    fn state_exits(state: State) -> bool {
+        matches!(
+            state,
+            State::Jumping
+                | State::Dashing
+                | State::Rowing
+                | State::Catching
+                | State::Caught
+                | State::Falling
+                | State::Ice
+                | State::Lying
+                | State::Burning
+                | State::Drinking
+                | State::HitGround
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::FrameLoops;
+    use crate::{FrameLoopKind, FrameNumber, ObjectData, ObjectType};
+
+    #[test]
+    fn ball_staying_on_one_frame_is_a_trap_and_flight_loop_is_idle() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 flying\n  pic: 0  state: 3000  wait: 1  next: 0\n<frame_end>\n\
+            <frame> 1 flying\n  pic: 0  state: 3005  wait: 1  next: 2\n<frame_end>\n\
+            <frame> 2 flying\n  pic: 0  state: 3005  wait: 1  next: 1\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let frame_loops = FrameLoops::find(ObjectType::Attack, &object_data.frames)
+            .into_iter()
+            .map(|frame_loop| (frame_loop.frame_numbers, frame_loop.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (vec![FrameNumber(0)], FrameLoopKind::Trap),
+                (vec![FrameNumber(1), FrameNumber(2)], FrameLoopKind::Idle),
+            ],
+            frame_loops
+        );
+    }
+
+    #[test]
+    fn frames_the_game_enters_may_hold_with_next_0() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+
+        let frame_loops = FrameLoops::find(ObjectType::Character, &object_data.frames);
+        let kind = |frame_number: usize| {
+            frame_loops
+                .iter()
+                .find(|frame_loop| frame_loop.frame_numbers == [FrameNumber(frame_number)])
+                .map(|frame_loop| frame_loop.kind)
+        };
+
+        // dash attack, dash defend, and tired
+        [94, 95, 207].iter().for_each(|frame_number| {
+            assert_eq!(
+                Some(FrameLoopKind::Escapable),
+                kind(*frame_number),
+                "frame {}",
+                frame_number
+            );
+        });
+        assert!(FrameLoops::check(ObjectType::Character, &object_data.frames).is_empty());
+    }
+}
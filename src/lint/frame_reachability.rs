@@ -0,0 +1,377 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    BdyKind, Diagnostic, Element, Frame, FrameNumber, FrameNumberNext, Frames, GameData, ItrKind,
+    ObjectId, ObjectType, Severity, WPointKind,
+};
+
+/// Frames that can and cannot be reached from the frames the game enters.
+///
+/// A frame is reachable when it is an engine entry frame for the object's
+/// type, is entered by another object, or is referenced by a reachable frame's
+/// `next`, `hit_*`, `itr` or `cpoint` actions, or hostage `bdy` freed frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameReachability {
+    /// Frames that can be reached.
+    pub reachable: BTreeSet<FrameNumber>,
+    /// Frames that cannot be reached, in ascending order.
+    pub orphans: Vec<FrameNumber>,
+    /// Orphans that no other frame refers to, so removing them does not leave
+    /// references to missing frames.
+    pub removal_safe: Vec<FrameNumber>,
+}
+
+impl FrameReachability {
+    /// A frame cannot be reached.
+    pub const FRAME_UNREACHABLE: &'static str = "frame-unreachable";
+    /// Character frames that the game uses while carrying a heavy weapon.
+    ///
+    /// These are heavy weapon walking, running, and stopping, heavy weapon
+    /// throwing, and picking up a heavy weapon.
+    pub const HEAVY_WEAPON_FRAMES: [usize; 12] = [12, 13, 14, 15, 16, 17, 18, 19, 50, 51, 116, 117];
+
+    /// Returns the reachability of an object's frames.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_type`: Type of the object, which determines the engine entry
+    ///   frames.
+    /// * `frames`: Frames of the object.
+    /// * `entries_external`: Frames that other objects switch this object to,
+    ///   see [`FrameReachability::entries_external`].
+    pub fn new(object_type: ObjectType, frames: &Frames, entries_external: &[FrameNumber]) -> Self {
+        let mut reachable = BTreeSet::new();
+        let mut pending = Self::entries_engine(object_type)
+            .into_iter()
+            .chain(entries_external.iter().copied())
+            .collect::<Vec<FrameNumber>>();
+        while let Some(frame_number) = pending.pop() {
+            if !reachable.insert(frame_number) {
+                continue;
+            }
+            if let Some(frame) = frames.get(frame_number) {
+                pending.extend(Self::references(frame));
+            }
+        }
+
+        let orphans = frames
+            .iter()
+            .map(|frame| frame.number)
+            .filter(|frame_number| !reachable.contains(frame_number))
+            .collect::<BTreeSet<FrameNumber>>();
+        let referenced = frames
+            .iter()
+            .filter(|frame| orphans.contains(&frame.number))
+            .flat_map(|frame| {
+                Self::references(frame).filter(move |frame_number| *frame_number != frame.number)
+            })
+            .collect::<BTreeSet<FrameNumber>>();
+        let removal_safe = orphans
+            .iter()
+            .copied()
+            .filter(|frame_number| !referenced.contains(frame_number))
+            .collect();
+
+        Self {
+            reachable,
+            orphans: orphans.into_iter().collect(),
+            removal_safe,
+        }
+    }
+
+    /// Returns the frames of an object that other objects switch it to.
+    ///
+    /// This includes `opoint` actions that spawn the object, and for characters,
+    /// the frames that catching characters put them in. For weapons, the held
+    /// frames from characters' holding `wpoint`s are included.
+    ///
+    /// Characters hold heavy weapons in the frames the game uses for carrying
+    /// and throwing them, see [`FrameReachability::HEAVY_WEAPON_FRAMES`], and
+    /// light weapons in all other frames. A custom frame that is only entered
+    /// while carrying a heavy weapon is counted as holding a light weapon, so
+    /// light weapons may have more entry frames than the game uses.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_data`: All objects in the game.
+    /// * `object_id`: ID of the object whose frames are entered.
+    pub fn entries_external(game_data: &GameData, object_id: ObjectId) -> Vec<FrameNumber> {
+        let object_type = game_data
+            .get(object_id)
+            .map(|game_object| game_object.object_type)
+            .unwrap_or_default();
+        let weapon_heavy = object_type == ObjectType::HeavyWeapon;
+        let weapon = matches!(
+            object_type,
+            ObjectType::LightWeapon | ObjectType::ThrowWeapon | ObjectType::Drink
+        ) || weapon_heavy;
+        let character = matches!(object_type, ObjectType::Character | ObjectType::Criminal);
+
+        let mut entries = game_data
+            .iter()
+            .flat_map(|game_object| {
+                let holder = matches!(
+                    game_object.object_type,
+                    ObjectType::Character | ObjectType::Criminal
+                );
+                game_object
+                    .object_data
+                    .frames
+                    .iter()
+                    .flat_map(move |frame| {
+                        frame
+                            .elements
+                            .iter()
+                            .map(move |element| (holder, frame, element))
+                    })
+            })
+            .flat_map(|(holder, frame, element)| match element {
+                Element::OPoint(o_point) if o_point.object_id == object_id => vec![o_point.action],
+                Element::WPoint(w_point)
+                    if weapon
+                        && holder
+                        && w_point.kind == WPointKind::Holding
+                        && Self::HEAVY_WEAPON_FRAMES.contains(&*frame.number) == weapon_heavy =>
+                {
+                    vec![w_point.weapon_act]
+                }
+                Element::Itr(itr)
+                    if character
+                        && matches!(itr.kind, ItrKind::CatchStunned | ItrKind::CatchForce) =>
+                {
+                    vec![itr.caught_act, itr.caught_act_back]
+                }
+                Element::CPoint(c_point) if character => {
+                    vec![FrameNumberNext(c_point.v_action.0 as isize)]
+                }
+                _ => vec![],
+            })
+            .filter_map(Self::frame_number)
+            .collect::<Vec<FrameNumber>>();
+        entries.sort();
+        entries.dedup();
+        entries
+    }
+
+    /// Returns diagnostics for each orphan frame.
+    pub fn check(&self, frames: &Frames) -> Vec<Diagnostic> {
+        self.orphans
+            .iter()
+            .filter_map(|frame_number| frames.get(*frame_number))
+            .map(|frame| {
+                let removal = if self.removal_safe.contains(&frame.number) {
+                    "It can be removed."
+                } else {
+                    "It can be removed together with the orphan frames that refer to it."
+                };
+                Diagnostic::new(
+                    Self::FRAME_UNREACHABLE,
+                    Severity::Warning,
+                    Some(frame.number),
+                    format!(
+                        "`{}` cannot be reached from any entry frame. {}",
+                        frame.name, removal
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the frames that the game switches an object to on its own.
+    pub(crate) fn entries_engine(object_type: ObjectType) -> Vec<FrameNumber> {
+        let ranges: &[(usize, usize)] = match object_type {
+            ObjectType::Character | ObjectType::Criminal => &[
+                // standing, walking, running, heavy weapon walking and running
+                (0, 19),
+                // weapon attacks and throws
+                (20, 20),
+                (25, 25),
+                (30, 30),
+                (35, 35),
+                (40, 40),
+                (45, 45),
+                (50, 50),
+                (52, 52),
+                // drinking
+                (55, 55),
+                // punches, super punch, jump, run and dash attacks
+                (60, 60),
+                (65, 65),
+                (70, 70),
+                (80, 80),
+                (85, 85),
+                (90, 90),
+                // dash attack and dash defend
+                (94, 95),
+                // rowing and rolling
+                (100, 102),
+                (108, 108),
+                // defend and broken defence
+                (110, 112),
+                // picking up weapons
+                (115, 116),
+                // catching and caught
+                (120, 144),
+                // falling, ice, fire, and tired
+                (180, 207),
+                // jump, dash, crouch, stop running
+                (210, 219),
+                // injured and stunned
+                (220, 229),
+                // lying, and thrown
+                (230, 234),
+            ],
+            ObjectType::LightWeapon | ObjectType::ThrowWeapon | ObjectType::Drink => &[
+                // in the sky, in hand, thrown, on the ground
+                (0, 15),
+                (20, 35),
+                (40, 55),
+                (60, 64),
+                (70, 74),
+            ],
+            // in the sky, in hand, thrown, on the ground
+            ObjectType::HeavyWeapon => &[(0, 5), (10, 10), (20, 21)],
+            // flying, hitting, hit, rebounding
+            ObjectType::Attack => &[(0, 0), (10, 10), (20, 20), (30, 30)],
+        };
+        ranges
+            .iter()
+            .flat_map(|&(first, last)| (first..=last).map(FrameNumber))
+            .collect()
+    }
+
+    /// Returns the frames that a frame refers to in the same object.
+    fn references(frame: &Frame) -> impl Iterator<Item = FrameNumber> + '_ {
+        let frame_numbers_next = [
+            frame.next_frame,
+            frame.hit_a,
+            frame.hit_d,
+            frame.hit_da,
+            frame.hit_dj,
+            frame.hit_fa,
+            frame.hit_fj,
+            frame.hit_j,
+            frame.hit_ja,
+            frame.hit_ua,
+            frame.hit_uj,
+        ];
+        let element_frame_numbers = frame.elements.iter().flat_map(|element| match element {
+            Element::Itr(itr) if itr.kind == ItrKind::HealBall => {
+                vec![FrameNumberNext(itr.d_vx as isize)]
+            }
+            Element::Itr(itr) => vec![itr.catching_act, itr.catching_act_back],
+            Element::Bdy(bdy) => match bdy.kind {
+                BdyKind::Hostage { freed_frame } => vec![freed_frame],
+                BdyKind::Normal => Vec::new(),
+            },
+            Element::CPoint(c_point) => vec![
+                c_point.a_action,
+                c_point.j_action,
+                c_point.t_action,
+                c_point.front_hurt_act,
+                c_point.back_hurt_act,
+            ],
+            _ => Vec::new(),
+        });
+
+        IntoIterator::into_iter(frame_numbers_next)
+            .chain(element_frame_numbers)
+            .filter_map(Self::frame_number)
+    }
+
+    /// Returns the frame that a `FrameNumberNext` switches to, if it refers to a
+    /// frame.
+    fn frame_number(frame_number_next: FrameNumberNext) -> Option<FrameNumber> {
+        match frame_number_next {
+            FrameNumberNext::NONE | FrameNumberNext::DELETE => None,
+            FrameNumberNext::STANDING => Some(FrameNumber(0)),
+            frame_number_next => Some(frame_number_next.abs()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::FrameReachability;
+    use crate::{FrameNumber, GameData, GameObject, ObjectData, ObjectId, ObjectType};
+
+    #[test]
+    fn orphans_referred_to_by_other_orphans_are_not_removal_safe() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 flying\n  pic: 0  wait: 1  next: 1\n<frame_end>\n\
+            <frame> 1 flying\n  pic: 0  wait: 1  next: 1000\n<frame_end>\n\
+            <frame> 40 spin\n  pic: 0  wait: 1  next: 41\n<frame_end>\n\
+            <frame> 41 spin\n  pic: 0  wait: 1  next: 40\n<frame_end>\n\
+            <frame> 50 unused\n  pic: 0  wait: 1  next: 0\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let frame_reachability =
+            FrameReachability::new(ObjectType::Attack, &object_data.frames, &[]);
+        assert_eq!(
+            vec![FrameNumber(40), FrameNumber(41), FrameNumber(50)],
+            frame_reachability.orphans
+        );
+        assert_eq!(vec![FrameNumber(50)], frame_reachability.removal_safe);
+
+        let frame_reachability =
+            FrameReachability::new(ObjectType::Attack, &object_data.frames, &[FrameNumber(41)]);
+        assert_eq!(vec![FrameNumber(50)], frame_reachability.orphans);
+    }
+
+    #[test]
+    fn weapons_enter_the_held_frames_of_their_weight() {
+        let holder_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 12 heavy_obj_walk\n  pic: 0  wait: 1  next: 0\n\
+            \x20  wpoint:\n      kind: 1  x: 40  y: 50  weaponact: 10\n   wpoint_end:\n\
+            <frame_end>\n\
+            <frame> 20 normal_weapon_atck\n  pic: 0  wait: 1  next: 0\n\
+            \x20  wpoint:\n      kind: 1  x: 40  y: 50  weaponact: 21\n   wpoint_end:\n\
+            <frame_end>\n\
+            <frame> 60 punch\n  pic: 0  wait: 1  next: 0\n\
+            \x20  wpoint:\n      kind: 3  x: 40  y: 50  weaponact: 40\n   wpoint_end:\n\
+            <frame_end>\n";
+        let weapon_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 in_the_sky\n  pic: 0  wait: 1  next: 0\n<frame_end>\n";
+        let mut game_data = GameData::new();
+        [
+            (ObjectId(1), ObjectType::Character, holder_str),
+            (ObjectId(100), ObjectType::LightWeapon, weapon_str),
+            (ObjectId(101), ObjectType::HeavyWeapon, weapon_str),
+        ]
+        .iter()
+        .for_each(|(id, object_type, object_data_str)| {
+            game_data.insert(GameObject {
+                id: *id,
+                object_type: *object_type,
+                object_data: ObjectData::try_from(*object_data_str).unwrap(),
+            });
+        });
+
+        assert_eq!(
+            vec![FrameNumber(21)],
+            FrameReachability::entries_external(&game_data, ObjectId(100))
+        );
+        assert_eq!(
+            vec![FrameNumber(10)],
+            FrameReachability::entries_external(&game_data, ObjectId(101))
+        );
+    }
+
+    #[test]
+    fn hostage_bdy_freed_frames_are_reachable() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 standing\n  pic: 0  wait: 1  next: 0\n\
+            \x20  bdy:\n      kind: 1300  x: 0  y: 0  w: 40  h: 80\n   bdy_end:\n\
+            <frame_end>\n\
+            <frame> 300 freed\n  pic: 0  wait: 1  next: 999\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let frame_reachability =
+            FrameReachability::new(ObjectType::Criminal, &object_data.frames, &[]);
+
+        assert!(frame_reachability.reachable.contains(&FrameNumber(300)));
+        assert!(frame_reachability.orphans.is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+
+use crate::{
+    Diagnostic, Effect, Element, Frame, FrameNumber, FrameNumberNext, Frames, Itr, ItrKind,
+    Severity,
+};
+
+/// Checks that `itr` fields are meaningful for the `itr`'s kind.
+#[derive(Debug)]
+pub struct ItrFields;
+
+impl ItrFields {
+    /// A field is set, but the game ignores it for this kind.
+    pub const ITR_FIELD_IGNORED: &'static str = "itr-field-ignored";
+    /// A field that this kind needs is not set.
+    pub const ITR_FIELD_MISSING: &'static str = "itr-field-missing";
+    /// A field refers to a frame that does not exist.
+    pub const ITR_FRAME_MISSING: &'static str = "itr-frame-missing";
+    /// A `kind: 6` super punch `itr` does not use `vrest: 1`.
+    pub const ITR_SUPER_PUNCH_V_REST: &'static str = "itr-super-punch-v-rest";
+
+    /// Returns diagnostics for `itr` fields that are ignored, missing, or refer
+    /// to frames that do not exist, based on each `itr`'s kind.
+    pub fn check(frames: &Frames) -> Vec<Diagnostic> {
+        frames
+            .iter()
+            .flat_map(|frame| {
+                frame
+                    .elements
+                    .iter()
+                    .filter_map(move |element| match element {
+                        Element::Itr(itr) => Some(Self::itr_check(frames, frame, itr)),
+                        _ => None,
+                    })
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn itr_check(frames: &Frames, frame: &Frame, itr: &Itr) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut diagnostic_push = |code, severity, message: String| {
+            diagnostics.push(Diagnostic::new(
+                code,
+                severity,
+                Some(frame.number),
+                format!(
+                    "`{}`'s `itr/kind: {}` {}",
+                    frame.name, itr.kind as u32, message
+                ),
+            ))
+        };
+
+        let fields_ignored = Self::fields_ignored(itr);
+        if !fields_ignored.is_empty() {
+            diagnostic_push(
+                Self::ITR_FIELD_IGNORED,
+                Severity::Warning,
+                format!(
+                    "sets {}, which {} ignored for this kind.",
+                    fields_ignored.join(", "),
+                    if fields_ignored.len() == 1 {
+                        "is"
+                    } else {
+                        "are"
+                    }
+                ),
+            );
+        }
+
+        match itr.kind {
+            ItrKind::CatchStunned | ItrKind::CatchForce => {
+                [
+                    ("catchingact", itr.catching_act, itr.catching_act_back),
+                    ("caughtact", itr.caught_act, itr.caught_act_back),
+                ]
+                .iter()
+                .for_each(|&(field, front, back)| {
+                    if front == FrameNumberNext::NONE {
+                        diagnostic_push(
+                            Self::ITR_FIELD_MISSING,
+                            Severity::Warning,
+                            format!("has no `{}`.", field),
+                        );
+                        return;
+                    }
+
+                    let frame_missing = [front, back]
+                        .iter()
+                        .any(|frame_number_next| !frames.contains(frame_number_next.abs()));
+                    if frame_missing {
+                        diagnostic_push(
+                            Self::ITR_FRAME_MISSING,
+                            Severity::Error,
+                            format!(
+                                "has `{}: {} {}`, which refers to a missing frame.",
+                                field, front, back
+                            ),
+                        );
+                    }
+                });
+            }
+            ItrKind::HealBall if itr.d_vx != 0 => {
+                let frame_exists = usize::try_from(itr.d_vx.abs())
+                    .map(|frame_number| frames.contains(FrameNumber(frame_number)))
+                    .unwrap_or(false);
+                if !frame_exists {
+                    diagnostic_push(
+                        Self::ITR_FRAME_MISSING,
+                        Severity::Error,
+                        format!(
+                            "switches to `dvx: {}` on hit, which is not a frame.",
+                            itr.d_vx
+                        ),
+                    );
+                }
+            }
+            ItrKind::SuperPunch if itr.v_rest != 1 => {
+                diagnostic_push(
+                    Self::ITR_SUPER_PUNCH_V_REST,
+                    Severity::Warning,
+                    format!(
+                        "has `vrest: {}`, but super punch `itr`s normally use `vrest: 1` to \
+                        affect all characters.",
+                        itr.v_rest
+                    ),
+                );
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+
+    /// Returns the names of fields that are set but ignored for the `itr`'s
+    /// kind.
+    fn fields_ignored(itr: &Itr) -> Vec<&'static str> {
+        let catch_kind = matches!(itr.kind, ItrKind::CatchStunned | ItrKind::CatchForce);
+        let (damage_ignored, injury_ignored, d_v_ignored) = match itr.kind {
+            ItrKind::WeaponPick | ItrKind::RollWeaponPick | ItrKind::Wall => (true, true, true),
+            ItrKind::CatchStunned | ItrKind::CatchForce => (true, true, false),
+            ItrKind::WeaponStrength => (false, true, false),
+            ItrKind::HealBall => (true, false, false),
+            _ => (false, false, false),
+        };
+
+        let mut fields = Vec::new();
+        if injury_ignored && itr.injury != 0 {
+            fields.push("`injury`");
+        }
+        if damage_ignored {
+            if itr.fall != 0 {
+                fields.push("`fall`");
+            }
+            if itr.b_defend != 0 {
+                fields.push("`bdefend`");
+            }
+            if itr.effect != Effect::Normal {
+                fields.push("`effect`");
+            }
+        }
+        if d_v_ignored {
+            if itr.d_vx != 0 {
+                fields.push("`dvx`");
+            }
+            if itr.d_vy != 0 {
+                fields.push("`dvy`");
+            }
+        }
+        if !catch_kind {
+            if itr.catching_act != FrameNumberNext::NONE {
+                fields.push("`catchingact`");
+            }
+            if itr.caught_act != FrameNumberNext::NONE {
+                fields.push("`caughtact`");
+            }
+        }
+
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::ItrFields;
+    use crate::ObjectData;
+
+    #[test]
+    fn catch_itr_reports_ignored_missing_and_dangling_fields() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 punch\n  pic: 0  wait: 1  next: 0\n\
+            \x20  itr:\n      kind: 1  x: 40  y: 16  w: 25  h: 65  injury: 30\n\
+            \x20     catchingact: 120 120\n   itr_end:\n\
+            <frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let codes = ItrFields::check(&object_data.frames)
+            .into_iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            vec![
+                ItrFields::ITR_FIELD_IGNORED,
+                ItrFields::ITR_FRAME_MISSING,
+                ItrFields::ITR_FIELD_MISSING,
+            ],
+            codes
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Diagnostic, Frames, Header, Severity, Sprites};
+
+/// Checks that frames' pics exist in the object's sprite sheets.
+#[derive(Debug)]
+pub struct PicUsage;
+
+impl PicUsage {
+    /// A frame's pic is not in any sprite sheet.
+    ///
+    /// The game draws nothing for these frames, which some objects use on
+    /// purpose for invisible frames.
+    pub const PIC_MISSING: &'static str = "pic-missing";
+    /// A sheet's `file(a-b)` range does not match its `row * col` cells.
+    pub const SPRITE_FILE_RANGE_MISMATCH: &'static str = "sprite-file-range-mismatch";
+    /// A sheet's image size does not match its cell layout.
+    pub const SPRITE_FILE_SIZE_MISMATCH: &'static str = "sprite-file-size-mismatch";
+    /// A sheet's cell layout describes an image too large to represent.
+    pub const SPRITE_FILE_SIZE_OVERFLOW: &'static str = "sprite-file-size-overflow";
+
+    /// Returns diagnostics for pics outside the sprite sheets, and sheets whose
+    /// pic range does not match their number of cells.
+    pub fn check(header: &Header, frames: &Frames) -> Vec<Diagnostic> {
+        let range_mismatches = header.sprite_files.iter().filter_map(|sprite_file| {
+            let pic_range = sprite_file.pic_range();
+            let pic_range_len = (pic_range.end() + 1).saturating_sub(*pic_range.start());
+            if pic_range_len == sprite_file.pic_count() {
+                return None;
+            }
+            Some(Diagnostic::new(
+                Self::SPRITE_FILE_RANGE_MISMATCH,
+                Severity::Warning,
+                None,
+                format!(
+                    "`{}` declares pics {}-{} ({} pics), but has {} cells (row: {} * col: {}).",
+                    sprite_file.path().display(),
+                    pic_range.start(),
+                    pic_range.end(),
+                    pic_range_len,
+                    sprite_file.pic_count(),
+                    sprite_file.row(),
+                    sprite_file.col()
+                ),
+            ))
+        });
+
+        let pics_missing = frames.iter().filter_map(|frame| {
+            let pic = frame.pic.abs();
+            let pic_found = header
+                .sprite_files
+                .iter()
+                .any(|sprite_file| sprite_file.pic_range().contains(&pic));
+            if pic_found {
+                return None;
+            }
+            Some(Diagnostic::new(
+                Self::PIC_MISSING,
+                Severity::Warning,
+                Some(frame.number),
+                format!(
+                    "`{}` uses pic {}, which is not in any sprite file.",
+                    frame.name, pic
+                ),
+            ))
+        });
+
+        range_mismatches.chain(pics_missing).collect()
+    }
+
+    /// Returns diagnostics for sheets whose image size does not match their
+    /// cell layout.
+    ///
+    /// `row` is the number of cells across, so an image must be `(w + 1) *
+    /// row` pixels wide and `(h + 1) * col` pixels tall.
+    pub fn check_sprites(sprites: &Sprites) -> Vec<Diagnostic> {
+        sprites
+            .sheets
+            .iter()
+            .filter_map(|sheet| {
+                let sprite_file = &sheet.sprite_file;
+                let size_expected = |cell_size: u32, count: u32| {
+                    cell_size
+                        .checked_add(1)
+                        .and_then(|cell_size| cell_size.checked_mul(count))
+                };
+                let (width_expected, height_expected) = match (
+                    size_expected(sprite_file.w(), sprite_file.row()),
+                    size_expected(sprite_file.h(), sprite_file.col()),
+                ) {
+                    (Some(width_expected), Some(height_expected)) => {
+                        (width_expected, height_expected)
+                    }
+                    _ => {
+                        return Some(Diagnostic::new(
+                            Self::SPRITE_FILE_SIZE_OVERFLOW,
+                            Severity::Error,
+                            None,
+                            format!(
+                                "`{}` has `w: {}  h: {}  row: {}  col: {}`, which is larger \
+                                 than an image can be.",
+                                sprite_file.path().display(),
+                                sprite_file.w(),
+                                sprite_file.h(),
+                                sprite_file.row(),
+                                sprite_file.col()
+                            ),
+                        ))
+                    }
+                };
+                if sheet.image.width == width_expected && sheet.image.height == height_expected {
+                    return None;
+                }
+                // Cells past the image edge are cut off, which is worse than extra space.
+                let severity =
+                    if sheet.image.width < width_expected || sheet.image.height < height_expected {
+                        Severity::Error
+                    } else {
+                        Severity::Warning
+                    };
+                Some(Diagnostic::new(
+                    Self::SPRITE_FILE_SIZE_MISMATCH,
+                    severity,
+                    None,
+                    format!(
+                        "`{}` is {}x{}, but `w: {}  h: {}  row: {}  col: {}` expects {}x{}.",
+                        sprite_file.path().display(),
+                        sheet.image.width,
+                        sheet.image.height,
+                        sprite_file.w(),
+                        sprite_file.h(),
+                        sprite_file.row(),
+                        sprite_file.col(),
+                        width_expected,
+                        height_expected
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::PicUsage;
+    use crate::{FrameNumber, ObjectData, SpriteImage, SpriteSheet, Sprites};
+
+    #[test]
+    fn reports_pics_outside_sheets_and_ranges_that_do_not_match_cells() {
+        let object_data_str = "<bmp_begin>\n\
+            file(0-9): sprite\\test_0.bmp  w: 79  h: 79  row: 5  col: 2\n\
+            file(10-29): sprite\\test_1.bmp  w: 79  h: 79  row: 5  col: 2\n\
+            <bmp_end>\n\
+            <frame> 0 standing\n  pic: 9  wait: 1  next: 0\n<frame_end>\n\
+            <frame> 1 standing\n  pic: 30  wait: 1  next: 0\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let diagnostics = PicUsage::check(&object_data.header, &object_data.frames)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code, diagnostic.frame_number))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (PicUsage::SPRITE_FILE_RANGE_MISMATCH, None),
+                (PicUsage::PIC_MISSING, Some(FrameNumber(1))),
+            ],
+            diagnostics
+        );
+    }
+
+    #[test]
+    fn reports_sheet_layouts_whose_size_overflows() {
+        let object_data_str = "<bmp_begin>\n\
+            file(0-9): sprite\\test_0.bmp  w: 79  h: 79  row: 5  col: 2\n\
+            file(10-19): sprite\\test_1.bmp  w: 4294967295  h: 79  row: 5  col: 2\n\
+            file(20-29): sprite\\test_2.bmp  w: 79  h: 2147483648  row: 5  col: 2\n\
+            <bmp_end>\n\
+            <frame> 0 standing\n  pic: 0  wait: 1  next: 0\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+        let sprites = Sprites {
+            sheets: object_data
+                .header
+                .sprite_files
+                .iter()
+                .map(|sprite_file| SpriteSheet {
+                    sprite_file: sprite_file.clone(),
+                    image: SpriteImage::new(400, 160),
+                })
+                .collect(),
+        };
+
+        let codes = PicUsage::check_sprites(&sprites)
+            .into_iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                PicUsage::SPRITE_FILE_SIZE_OVERFLOW,
+                PicUsage::SPRITE_FILE_SIZE_OVERFLOW,
+            ],
+            codes
+        );
+    }
+}
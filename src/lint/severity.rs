@@ -0,0 +1,22 @@
+use std::fmt::{self, Display};
+
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The game will misbehave or crash.
+    Error,
+    /// The data is likely a mistake, but the game can still run it.
+    Warning,
+    /// Informational note that does not need to be fixed.
+    Info,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+            Self::Info => write!(f, "info"),
+        }
+    }
+}
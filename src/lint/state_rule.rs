@@ -0,0 +1,35 @@
+use crate::{Diagnostic, FrameNumber, Frames, ObjectType, Severity};
+
+/// Function that checks frames against a [`StateRule`].
+///
+/// # Parameters
+///
+/// * `state_rule`: The rule being checked, used to create diagnostics.
+/// * `object_type`: Type of the object the frames belong to.
+/// * `frames`: Frames of the object.
+pub type StateRuleFn = fn(&StateRule, ObjectType, &Frames) -> Vec<Diagnostic>;
+
+/// Expectation about how frames use `state` values.
+#[derive(Clone, Copy, Debug)]
+pub struct StateRule {
+    /// Identifier of the rule, used as the code of its diagnostics.
+    pub id: &'static str,
+    /// Severity of the rule's diagnostics.
+    pub severity: Severity,
+    /// What the rule expects.
+    pub description: &'static str,
+    /// Function that checks frames against the rule.
+    pub check: StateRuleFn,
+}
+
+impl StateRule {
+    /// Returns diagnostics for frames that do not meet this rule.
+    pub fn check(&self, object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        (self.check)(self, object_type, frames)
+    }
+
+    /// Returns a diagnostic with this rule's ID and severity.
+    pub fn diagnostic(&self, frame_number: Option<FrameNumber>, message: String) -> Diagnostic {
+        Diagnostic::new(self.id, self.severity, frame_number, message)
+    }
+}
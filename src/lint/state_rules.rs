@@ -0,0 +1,424 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::{Diagnostic, FrameNumber, Frames, ObjectType, Severity, State, StateRule};
+
+/// Set of [`StateRule`]s to check frames against.
+///
+/// The default set contains the built in rules. Rules may be removed or have
+/// their severity changed before checking.
+#[derive(Clone, Debug)]
+pub struct StateRules(pub Vec<StateRule>);
+
+impl Default for StateRules {
+    fn default() -> Self {
+        StateRules(vec![
+            StateRule {
+                id: "standing-frames",
+                severity: Severity::Warning,
+                description: "Characters have `standing` frames 0-4 with `state: 0`.",
+                check: Self::standing_frames,
+            },
+            StateRule {
+                id: "walking-frames",
+                severity: Severity::Warning,
+                description: "Characters have `walking` frames 5-8 with `state: 1`.",
+                check: Self::walking_frames,
+            },
+            StateRule {
+                id: "running-frames",
+                severity: Severity::Warning,
+                description: "Characters have `running` frames 9-11 with `state: 2`.",
+                check: Self::running_frames,
+            },
+            StateRule {
+                id: "defend-frames",
+                severity: Severity::Warning,
+                description: "Characters have `defend` frames 110-111 with `state: 7`.",
+                check: Self::defend_frames,
+            },
+            StateRule {
+                id: "falling-frames",
+                severity: Severity::Warning,
+                description: "Characters have `falling` frames 180-191 with `state: 12`.",
+                check: Self::falling_frames,
+            },
+            StateRule {
+                id: "jump-frames",
+                severity: Severity::Warning,
+                description: "Characters have `jump` frames 210-212 with `state: 4`.",
+                check: Self::jump_frames,
+            },
+            StateRule {
+                id: "dash-frames",
+                severity: Severity::Warning,
+                description:
+                    "Characters have `dash` frames 213, 214, 216, and 217 with `state: 5`.",
+                check: Self::dash_frames,
+            },
+            StateRule {
+                id: "stunned-frames",
+                severity: Severity::Warning,
+                description: "Characters have stunned `injured` frames 226-229 with `state: 16`.",
+                check: Self::stunned_frames,
+            },
+            StateRule {
+                id: "lying-frames",
+                severity: Severity::Warning,
+                description: "Characters have `lying` frames 230-231 with `state: 14`.",
+                check: Self::lying_frames,
+            },
+            StateRule {
+                id: "transform-check-next",
+                severity: Severity::Warning,
+                description: "Frames with `state: 500` go to a frame with `state: 501`.",
+                check: Self::transform_check_next,
+            },
+            StateRule {
+                id: "transform-reverse-frame",
+                severity: Severity::Warning,
+                description: "Objects that transform back have frame 245, where reverse \
+                    transformation begins.",
+                check: Self::transform_reverse_frame,
+            },
+            StateRule {
+                id: "ball-state-type",
+                severity: Severity::Error,
+                description: "`state: 3000`-`3006` are only used in `type: 3` objects.",
+                check: Self::ball_state_type,
+            },
+            StateRule {
+                id: "weapon-state-type",
+                severity: Severity::Error,
+                description: "Light and heavy weapon states are only used in weapons of \
+                    that type.",
+                check: Self::weapon_state_type,
+            },
+        ])
+    }
+}
+
+impl StateRules {
+    /// Returns diagnostics from every rule.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_type`: Type of the object the frames belong to.
+    /// * `frames`: Frames of the object.
+    pub fn check(&self, object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        self.iter()
+            .flat_map(|state_rule| state_rule.check(object_type, frames))
+            .collect()
+    }
+
+    /// Returns the rule with the given ID.
+    pub fn rule(&self, id: &str) -> Option<&StateRule> {
+        self.iter().find(|state_rule| state_rule.id == id)
+    }
+
+    /// Returns a mutable reference to the rule with the given ID.
+    pub fn rule_mut(&mut self, id: &str) -> Option<&mut StateRule> {
+        self.iter_mut().find(|state_rule| state_rule.id == id)
+    }
+
+    fn standing_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[0, 1, 2, 3, 4],
+            "standing",
+            State::Standing,
+        )
+    }
+
+    fn walking_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[5, 6, 7, 8],
+            "walking",
+            State::Walking,
+        )
+    }
+
+    fn running_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[9, 10, 11],
+            "running",
+            State::Running,
+        )
+    }
+
+    fn defend_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[110, 111],
+            "defend",
+            State::Defend,
+        )
+    }
+
+    fn falling_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        let frame_numbers = (180..=191).collect::<Vec<usize>>();
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &frame_numbers,
+            "falling",
+            State::Falling,
+        )
+    }
+
+    fn jump_frames(rule: &StateRule, object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[210, 211, 212],
+            "jump",
+            State::Jumping,
+        )
+    }
+
+    fn dash_frames(rule: &StateRule, object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[213, 214, 216, 217],
+            "dash",
+            State::Dashing,
+        )
+    }
+
+    fn stunned_frames(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[226, 227, 228, 229],
+            "injured",
+            State::Stunned,
+        )
+    }
+
+    fn lying_frames(rule: &StateRule, object_type: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        Self::frames_expected(
+            rule,
+            object_type,
+            frames,
+            &[230, 231],
+            "lying",
+            State::Lying,
+        )
+    }
+
+    /// Returns diagnostics for hardcoded character frames that are missing or
+    /// use a different state.
+    fn frames_expected(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+        frame_numbers: &[usize],
+        name: &str,
+        state: State,
+    ) -> Vec<Diagnostic> {
+        if object_type != ObjectType::Character {
+            return Vec::new();
+        }
+        frame_numbers
+            .iter()
+            .map(|&frame_number| FrameNumber(frame_number))
+            .filter_map(|frame_number| match frames.get(frame_number) {
+                Some(frame) if frame.state == state => None,
+                Some(frame) => Some(rule.diagnostic(
+                    Some(frame_number),
+                    format!(
+                        "Frame {} `{}` is `state: {}`, expected `{}` `state: {}`.",
+                        frame_number, frame.name, frame.state as u32, name, state as u32
+                    ),
+                )),
+                None => Some(rule.diagnostic(
+                    Some(frame_number),
+                    format!(
+                        "Frame {} `{}` is missing, expected `state: {}`.",
+                        frame_number, name, state as u32
+                    ),
+                )),
+            })
+            .collect()
+    }
+
+    fn transform_check_next(rule: &StateRule, _: ObjectType, frames: &Frames) -> Vec<Diagnostic> {
+        frames
+            .iter()
+            .filter(|frame| frame.state == State::TransformCheck)
+            .filter_map(|frame| {
+                let transforms = frames
+                    .get(frame.next_frame.abs())
+                    .map(|frame_next| frame_next.state == State::Transform)
+                    .unwrap_or(false);
+                if transforms {
+                    return None;
+                }
+                Some(rule.diagnostic(
+                    Some(frame.number),
+                    format!(
+                        "`{}` checks for a transformation with `state: 500`, but `next: {}` \
+                        is not a `state: 501` frame.",
+                        frame.name, frame.next_frame
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    fn transform_reverse_frame(
+        rule: &StateRule,
+        _: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        let transforms = frames
+            .iter()
+            .any(|frame| frame.state == State::TransformCheck || frame.state == State::Transform);
+        if !transforms || frames.get(Self::FRAME_TRANSFORM_REVERSE).is_some() {
+            return Vec::new();
+        }
+        vec![rule.diagnostic(
+            None,
+            format!(
+                "Frames use `state: 500` or `501`, but frame {} where reverse transformation \
+                begins is missing.",
+                Self::FRAME_TRANSFORM_REVERSE
+            ),
+        )]
+    }
+
+    fn ball_state_type(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        if object_type == ObjectType::Attack {
+            return Vec::new();
+        }
+        Self::states_unexpected(rule, object_type, frames, |state| {
+            (State::BallFlying as u32..=State::BallFlyingPiercing as u32).contains(&(state as u32))
+        })
+    }
+
+    fn weapon_state_type(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+    ) -> Vec<Diagnostic> {
+        let light_weapon = matches!(
+            object_type,
+            ObjectType::LightWeapon | ObjectType::ThrowWeapon | ObjectType::Drink
+        );
+        let heavy_weapon = object_type == ObjectType::HeavyWeapon;
+        Self::states_unexpected(rule, object_type, frames, |state| match state as u32 {
+            1000..=1004 => !light_weapon,
+            2000..=2004 => !heavy_weapon,
+            _ => false,
+        })
+    }
+
+    /// Returns diagnostics for frames whose state is not expected for the
+    /// object type.
+    fn states_unexpected(
+        rule: &StateRule,
+        object_type: ObjectType,
+        frames: &Frames,
+        unexpected: impl Fn(State) -> bool,
+    ) -> Vec<Diagnostic> {
+        frames
+            .iter()
+            .filter(|frame| unexpected(frame.state))
+            .map(|frame| {
+                rule.diagnostic(
+                    Some(frame.number),
+                    format!(
+                        "`{}` uses `state: {}`, which has no effect in `type: {}` objects.",
+                        frame.name, frame.state as u32, object_type as u32
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Frame that reverse transformation begins on.
+    const FRAME_TRANSFORM_REVERSE: FrameNumber = FrameNumber(245);
+}
+
+impl Deref for StateRules {
+    type Target = Vec<StateRule>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StateRules {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::StateRules;
+    use crate::{ObjectData, ObjectType, Severity};
+
+    #[test]
+    fn ball_state_in_weapon_uses_rule_severity() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <frame> 0 in_the_sky\n  pic: 0  state: 3000  wait: 1  next: 0\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+        let mut state_rules = StateRules::default();
+
+        let diagnostics = state_rules.check(ObjectType::LightWeapon, &object_data.frames);
+        assert_eq!(1, diagnostics.len());
+        assert_eq!("ball-state-type", diagnostics[0].code);
+        assert_eq!(Severity::Error, diagnostics[0].severity);
+
+        if let Some(state_rule) = state_rules.rule_mut("ball-state-type") {
+            state_rule.severity = Severity::Warning;
+        }
+        let diagnostics = state_rules.check(ObjectType::LightWeapon, &object_data.frames);
+        assert_eq!(Severity::Warning, diagnostics[0].severity);
+    }
+}
@@ -6,13 +6,17 @@ use std::{
 };
 
 use lf2_codec::DataDecoder;
-use pest::{iterators::Pair, Parser};
+use pest::iterators::Pair;
 
-use crate::{Error, Frames, Header, ObjectDataParser, Rule, SubRuleFn};
+use crate::{
+    Error, Frames, Header, ObjectDataParser, ParseOptions, ParseOutput, Rule, SubRuleWrapper,
+    WeaponStrengthList,
+};
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ObjectData {
     pub header: Header,
+    pub weapon_strength_list: WeaponStrengthList,
     pub frames: Frames,
 }
 
@@ -54,26 +58,54 @@ impl ObjectData {
     }
 }
 
+impl ObjectData {
+    /// Parses object data, following the given options.
+    pub(crate) fn parse_with_options<'i>(
+        pair: Pair<'i, Rule>,
+        options: &ParseOptions,
+    ) -> Result<ParseOutput, Error<'i>> {
+        // The weapon strength list is optional, so each section is matched by its rule.
+        let sub_rule_fn = SubRuleWrapper::new(
+            |mut parse_output: ParseOutput, section_pair: Pair<'i, Rule>| match section_pair
+                .as_rule()
+            {
+                Rule::Header => Header::try_from(section_pair).map(|header| {
+                    parse_output.object_data.header = header;
+                    parse_output
+                }),
+                Rule::WeaponStrengthList => {
+                    WeaponStrengthList::try_from(section_pair).map(|weapon_strength_list| {
+                        parse_output.object_data.weapon_strength_list = weapon_strength_list;
+                        parse_output
+                    })
+                }
+                Rule::Frames => Frames::parse_with_options(section_pair, options).map(
+                    |(frames, diagnostics, frame_spans)| {
+                        parse_output.object_data.frames = frames;
+                        parse_output.diagnostics.extend(diagnostics);
+                        parse_output.frame_spans = frame_spans;
+                        parse_output
+                    },
+                ),
+                _ => Ok(parse_output),
+            },
+        );
+
+        ObjectDataParser::parse_as_type(
+            ParseOutput::default(),
+            pair,
+            Rule::Object,
+            std::iter::repeat(&sub_rule_fn),
+        )
+    }
+}
+
 impl<'i> TryFrom<Pair<'i, Rule>> for ObjectData {
     type Error = Error<'i>;
 
     fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
-        let sub_rule_fns: &[SubRuleFn<ObjectData>] = &[
-            |mut object_data, header_pair| {
-                Header::try_from(header_pair).map(|header| {
-                    object_data.header = header;
-                    object_data
-                })
-            },
-            |mut object_data, frames_pair| {
-                Frames::try_from(frames_pair).map(|frames| {
-                    object_data.frames = frames;
-                    object_data
-                })
-            },
-        ];
-
-        ObjectDataParser::parse_as_type(ObjectData::default(), pair, Rule::Object, sub_rule_fns)
+        Self::parse_with_options(pair, &ParseOptions::default())
+            .map(|parse_output| parse_output.object_data)
     }
 }
 
@@ -81,20 +113,8 @@ impl<'s> TryFrom<&'s str> for ObjectData {
     type Error = Error<'s>;
 
     fn try_from(object_data_str: &'s str) -> Result<Self, Self::Error> {
-        let mut object_data_pairs = ObjectDataParser::parse(Rule::Object, object_data_str)?;
-        let object_data = object_data_pairs
-            .next()
-            .ok_or(Error::ObjectDataExpected)
-            .and_then(ObjectData::try_from)?;
-
-        // We should not have another pair.
-        if object_data_pairs.peek().is_some() {
-            Err(Error::ObjectDataSurplus {
-                object_data,
-                surplus_pairs: object_data_pairs,
-            })
-        } else {
-            Ok(object_data)
-        }
+        ObjectDataParser::default()
+            .object_data_parse(object_data_str)
+            .map(|parse_output| parse_output.object_data)
     }
 }
@@ -0,0 +1,531 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    Element, Frame, FrameNumber, Frames, Header, ObjectData, WeaponStrength, WeaponStrengthList,
+};
+
+pub use self::{field_change::FieldChange, frame_diff::FrameDiff};
+
+mod field_change;
+mod frame_diff;
+
+/// Fields of a value, as names and displayed values.
+type Fields = Vec<(&'static str, String)>;
+
+/// Gameplay relevant differences between two object data.
+///
+/// Frames are matched by frame number. Elements within a frame are matched by
+/// their kind and their index among elements of that kind, so `itr[1]` is
+/// compared with the second `itr` of the other frame. Weapon strength entries
+/// are matched by their `entry` number.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectDataDiff {
+    /// Changed header fields.
+    pub header: Vec<FieldChange>,
+    /// Changed weapon strength entries, as `entry[n]` fields.
+    pub weapon_strength_list: Vec<FieldChange>,
+    /// Frames that only exist after the change, by frame number.
+    pub frames_added: Vec<FrameNumber>,
+    /// Frames that only exist before the change, by frame number.
+    pub frames_removed: Vec<FrameNumber>,
+    /// Frames that exist in both, but differ, by frame number.
+    pub frames_changed: Vec<FrameDiff>,
+}
+
+impl ObjectDataDiff {
+    /// Returns the differences between two object data.
+    ///
+    /// # Parameters
+    ///
+    /// * `before`: Object data before the change.
+    /// * `after`: Object data after the change.
+    pub fn new(before: &ObjectData, after: &ObjectData) -> Self {
+        let mut header = Vec::new();
+        Self::fields_diff(
+            "",
+            &Self::header_fields(&before.header),
+            &Self::header_fields(&after.header),
+            &mut header,
+        );
+        let weapon_strength_list = Self::weapon_strength_list_changes(
+            &before.weapon_strength_list,
+            &after.weapon_strength_list,
+        );
+
+        let frame_numbers = |frames: &Frames| {
+            let mut frame_numbers = frames
+                .iter()
+                .map(|frame| frame.number)
+                .collect::<Vec<FrameNumber>>();
+            frame_numbers.sort_unstable();
+            frame_numbers
+        };
+        let frames_added = frame_numbers(&after.frames)
+            .into_iter()
+            .filter(|frame_number| !before.frames.contains(*frame_number))
+            .collect();
+        let frames_removed = frame_numbers(&before.frames)
+            .into_iter()
+            .filter(|frame_number| !after.frames.contains(*frame_number))
+            .collect();
+        let frames_changed = frame_numbers(&before.frames)
+            .into_iter()
+            .filter_map(|frame_number| {
+                let frame_before = before.frames.get(frame_number)?;
+                let frame_after = after.frames.get(frame_number)?;
+                let changes = Self::frame_changes(frame_before, frame_after);
+                if changes.is_empty() {
+                    None
+                } else {
+                    Some(FrameDiff {
+                        frame_number,
+                        name: frame_after.name.clone(),
+                        changes,
+                    })
+                }
+            })
+            .collect();
+
+        ObjectDataDiff {
+            header,
+            weapon_strength_list,
+            frames_added,
+            frames_removed,
+            frames_changed,
+        }
+    }
+
+    /// Returns whether there are no differences.
+    pub fn is_empty(&self) -> bool {
+        self.header.is_empty()
+            && self.weapon_strength_list.is_empty()
+            && self.frames_added.is_empty()
+            && self.frames_removed.is_empty()
+            && self.frames_changed.is_empty()
+    }
+
+    /// Returns the changes between two weapon strength lists, matching entries
+    /// by their `entry` number.
+    fn weapon_strength_list_changes(
+        before: &WeaponStrengthList,
+        after: &WeaponStrengthList,
+    ) -> Vec<FieldChange> {
+        let mut entries = before
+            .iter()
+            .chain(after.iter())
+            .map(|weapon_strength| weapon_strength.entry)
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+        entries.dedup();
+
+        let mut changes = Vec::new();
+        entries.into_iter().for_each(|entry| {
+            let fields_before = before
+                .iter()
+                .find(|weapon_strength| weapon_strength.entry == entry)
+                .map(|weapon_strength| Self::weapon_strength_fields(before, weapon_strength));
+            let fields_after = after
+                .iter()
+                .find(|weapon_strength| weapon_strength.entry == entry)
+                .map(|weapon_strength| Self::weapon_strength_fields(after, weapon_strength));
+            let prefix = format!("entry[{}]", entry);
+            match (&fields_before, &fields_after) {
+                (Some(fields_before), Some(fields_after)) => Self::fields_diff(
+                    &format!("{}.", prefix),
+                    fields_before,
+                    fields_after,
+                    &mut changes,
+                ),
+                (fields_before, fields_after) => changes.push(FieldChange {
+                    field: prefix,
+                    before: fields_before.as_ref().map(Self::fields_summary),
+                    after: fields_after.as_ref().map(Self::fields_summary),
+                }),
+            }
+        });
+
+        changes
+    }
+
+    /// Returns the changes between two frames with the same number.
+    fn frame_changes(before: &Frame, after: &Frame) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        Self::fields_diff(
+            "",
+            &Self::frame_fields(before),
+            &Self::frame_fields(after),
+            &mut changes,
+        );
+
+        ["bdy", "bpoint", "cpoint", "itr", "opoint", "wpoint"]
+            .iter()
+            .for_each(|kind| {
+                let elements_of_kind = |frame: &Frame| {
+                    frame
+                        .elements
+                        .iter()
+                        .map(Self::element_fields)
+                        .filter(|(element_kind, _)| element_kind == kind)
+                        .map(|(_, fields)| fields)
+                        .collect::<Vec<Fields>>()
+                };
+                let elements_before = elements_of_kind(before);
+                let elements_after = elements_of_kind(after);
+
+                let count = elements_before.len().max(elements_after.len());
+                (0..count).for_each(|index| {
+                    let prefix = format!("{}[{}]", kind, index);
+                    match (elements_before.get(index), elements_after.get(index)) {
+                        (Some(fields_before), Some(fields_after)) => Self::fields_diff(
+                            &format!("{}.", prefix),
+                            fields_before,
+                            fields_after,
+                            &mut changes,
+                        ),
+                        (fields_before, fields_after) => changes.push(FieldChange {
+                            field: prefix,
+                            before: fields_before.map(Self::fields_summary),
+                            after: fields_after.map(Self::fields_summary),
+                        }),
+                    }
+                });
+            });
+
+        changes
+    }
+
+    /// Appends a change for each field whose value differs.
+    ///
+    /// Both lists must be the fields of the same type.
+    fn fields_diff(prefix: &str, before: &Fields, after: &Fields, changes: &mut Vec<FieldChange>) {
+        before
+            .iter()
+            .zip(after.iter())
+            .filter(|((_, value_before), (_, value_after))| value_before != value_after)
+            .for_each(|((field, value_before), (_, value_after))| {
+                changes.push(FieldChange {
+                    field: format!("{}{}", prefix, field),
+                    before: Some(value_before.clone()),
+                    after: Some(value_after.clone()),
+                })
+            });
+    }
+
+    /// Returns fields as `name: value` pairs on one line.
+    fn fields_summary(fields: &Fields) -> String {
+        fields
+            .iter()
+            .map(|(field, value)| format!("{}: {}", field, value))
+            .collect::<Vec<String>>()
+            .join("  ")
+    }
+
+    fn header_fields(header: &Header) -> Fields {
+        let mut fields = vec![
+            ("name", header.name.clone()),
+            ("head", header.head.display().to_string()),
+            ("small", header.small.display().to_string()),
+            ("walking_frame_rate", header.walking_frame_rate.to_string()),
+            ("walking_speed", header.walking_speed.to_string()),
+            ("walking_speedz", header.walking_speed_z.to_string()),
+            ("running_frame_rate", header.running_frame_rate.to_string()),
+            ("running_speed", header.running_speed.to_string()),
+            ("running_speedz", header.running_speed_z.to_string()),
+            (
+                "heavy_walking_speed",
+                header.heavy_walking_speed.to_string(),
+            ),
+            (
+                "heavy_walking_speedz",
+                header.heavy_walking_speed_z.to_string(),
+            ),
+            (
+                "heavy_running_speed",
+                header.heavy_running_speed.to_string(),
+            ),
+            (
+                "heavy_running_speedz",
+                header.heavy_running_speed_z.to_string(),
+            ),
+            ("jump_height", header.jump_height.to_string()),
+            ("jump_distance", header.jump_distance.to_string()),
+            ("jump_distancez", header.jump_distance_z.to_string()),
+            ("dash_height", header.dash_height.to_string()),
+            ("dash_distance", header.dash_distance.to_string()),
+            ("dash_distancez", header.dash_distance_z.to_string()),
+            ("rowing_height", header.rowing_height.to_string()),
+            ("rowing_distance", header.rowing_distance.to_string()),
+        ];
+        fields.push((
+            "files",
+            header
+                .sprite_files
+                .iter()
+                .map(|sprite_file| {
+                    format!(
+                        "{} {:?} w: {} h: {} row: {} col: {}",
+                        sprite_file.path().display(),
+                        sprite_file.pic_range(),
+                        sprite_file.w(),
+                        sprite_file.h(),
+                        sprite_file.row(),
+                        sprite_file.col()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+        fields
+    }
+
+    fn weapon_strength_fields(
+        weapon_strength_list: &WeaponStrengthList,
+        weapon_strength: &WeaponStrength,
+    ) -> Fields {
+        vec![
+            (
+                "name",
+                weapon_strength_list
+                    .name(weapon_strength.entry)
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            ("dvx", weapon_strength.d_vx.to_string()),
+            ("dvy", weapon_strength.d_vy.to_string()),
+            ("arest", weapon_strength.arest.to_string()),
+            ("vrest", weapon_strength.vrest.to_string()),
+            ("fall", weapon_strength.fall.to_string()),
+            ("bdefend", weapon_strength.b_defend.to_string()),
+            ("injury", weapon_strength.injury.to_string()),
+            ("effect", format!("{:?}", weapon_strength.effect)),
+        ]
+    }
+
+    fn frame_fields(frame: &Frame) -> Fields {
+        vec![
+            ("name", frame.name.clone()),
+            ("pic", frame.pic.to_string()),
+            ("state", format!("{:?}", frame.state)),
+            ("wait", frame.wait.to_string()),
+            ("next", frame.next_frame.to_string()),
+            ("dvx", frame.d_vx.to_string()),
+            ("dvy", frame.d_vy.to_string()),
+            ("dvz", frame.d_vz.to_string()),
+            ("centerx", frame.center_x.to_string()),
+            ("centery", frame.center_y.to_string()),
+            ("hit_a", frame.hit_a.to_string()),
+            ("hit_d", frame.hit_d.to_string()),
+            ("hit_j", frame.hit_j.to_string()),
+            ("hit_Fa", frame.hit_fa.to_string()),
+            ("hit_Ua", frame.hit_ua.to_string()),
+            ("hit_Da", frame.hit_da.to_string()),
+            ("hit_Fj", frame.hit_fj.to_string()),
+            ("hit_Uj", frame.hit_uj.to_string()),
+            ("hit_Dj", frame.hit_dj.to_string()),
+            ("hit_ja", frame.hit_ja.to_string()),
+            ("mp", frame.mp.to_string()),
+            (
+                "sound",
+                frame
+                    .sound
+                    .as_ref()
+                    .map(|sound| sound.display().to_string())
+                    .unwrap_or_default(),
+            ),
+        ]
+    }
+
+    /// Returns the tag name of an element's kind, and its fields.
+    fn element_fields(element: &Element) -> (&'static str, Fields) {
+        match element {
+            Element::Bdy(bdy) => (
+                "bdy",
+                vec![
+                    ("kind", format!("{:?}", bdy.kind)),
+                    ("x", bdy.x.to_string()),
+                    ("y", bdy.y.to_string()),
+                    ("w", bdy.w.to_string()),
+                    ("h", bdy.h.to_string()),
+                    ("zwidth", bdy.z_width.to_string()),
+                ],
+            ),
+            Element::BPoint(b_point) => (
+                "bpoint",
+                vec![("x", b_point.x.to_string()), ("y", b_point.y.to_string())],
+            ),
+            Element::CPoint(c_point) => (
+                "cpoint",
+                vec![
+                    ("kind", format!("{:?}", c_point.kind)),
+                    ("x", c_point.x.to_string()),
+                    ("y", c_point.y.to_string()),
+                    ("cover", c_point.cover.to_string()),
+                    ("decrease", c_point.decrease.to_string()),
+                    ("dircontrol", c_point.dir_control.to_string()),
+                    ("hurtable", c_point.hurtable.to_string()),
+                    ("injury", c_point.injury.to_string()),
+                    ("aaction", c_point.a_action.to_string()),
+                    ("jaction", c_point.j_action.to_string()),
+                    ("vaction", c_point.v_action.to_string()),
+                    ("taction", c_point.t_action.to_string()),
+                    ("throwinjury", c_point.throw_injury.to_string()),
+                    ("throwvx", c_point.throw_vx.to_string()),
+                    ("throwvy", c_point.throw_vy.to_string()),
+                    ("throwvz", c_point.throw_vz.to_string()),
+                    ("fronthurtact", c_point.front_hurt_act.to_string()),
+                    ("backhurtact", c_point.back_hurt_act.to_string()),
+                ],
+            ),
+            Element::Itr(itr) => (
+                "itr",
+                vec![
+                    ("kind", format!("{:?}", itr.kind)),
+                    ("x", itr.x.to_string()),
+                    ("y", itr.y.to_string()),
+                    ("w", itr.w.to_string()),
+                    ("h", itr.h.to_string()),
+                    ("zwidth", itr.z_width.to_string()),
+                    ("dvx", itr.d_vx.to_string()),
+                    ("dvy", itr.d_vy.to_string()),
+                    ("arest", itr.a_rest.to_string()),
+                    ("vrest", itr.v_rest.to_string()),
+                    ("fall", itr.fall.to_string()),
+                    ("bdefend", itr.b_defend.to_string()),
+                    ("injury", itr.injury.to_string()),
+                    ("effect", format!("{:?}", itr.effect)),
+                    (
+                        "catchingact",
+                        format!("{} {}", itr.catching_act, itr.catching_act_back),
+                    ),
+                    (
+                        "caughtact",
+                        format!("{} {}", itr.caught_act, itr.caught_act_back),
+                    ),
+                ],
+            ),
+            Element::OPoint(o_point) => (
+                "opoint",
+                vec![
+                    ("kind", format!("{:?}", o_point.kind)),
+                    ("x", o_point.x.to_string()),
+                    ("y", o_point.y.to_string()),
+                    ("action", o_point.action.to_string()),
+                    ("dvx", o_point.d_vx.to_string()),
+                    ("dvy", o_point.d_vy.to_string()),
+                    ("oid", o_point.object_id.to_string()),
+                    (
+                        "facing",
+                        format!("{:?} x{}", o_point.facing.direction, o_point.facing.count),
+                    ),
+                ],
+            ),
+            Element::WPoint(w_point) => (
+                "wpoint",
+                vec![
+                    ("kind", format!("{:?}", w_point.kind)),
+                    ("x", w_point.x.to_string()),
+                    ("y", w_point.y.to_string()),
+                    ("weaponact", w_point.weapon_act.to_string()),
+                    ("attacking", w_point.attacking.to_string()),
+                    ("dvx", w_point.d_vx.to_string()),
+                    ("dvy", w_point.d_vy.to_string()),
+                    ("dvz", w_point.d_vz.to_string()),
+                ],
+            ),
+        }
+    }
+}
+
+impl Display for ObjectDataDiff {
+    /// Writes the differences as a Markdown list per section.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.header.is_empty() {
+            writeln!(f, "## Header\n")?;
+            self.header
+                .iter()
+                .try_for_each(|change| writeln!(f, "- {}", change))?;
+            writeln!(f)?;
+        }
+
+        if !self.weapon_strength_list.is_empty() {
+            writeln!(f, "## Weapon strength list\n")?;
+            self.weapon_strength_list
+                .iter()
+                .try_for_each(|change| writeln!(f, "- {}", change))?;
+            writeln!(f)?;
+        }
+
+        [
+            ("Frames added", &self.frames_added),
+            ("Frames removed", &self.frames_removed),
+        ]
+        .iter()
+        .filter(|(_, frame_numbers)| !frame_numbers.is_empty())
+        .try_for_each(|(heading, frame_numbers)| {
+            writeln!(f, "## {}\n", heading)?;
+            frame_numbers
+                .iter()
+                .try_for_each(|frame_number| writeln!(f, "- {}", frame_number))?;
+            writeln!(f)
+        })?;
+
+        self.frames_changed.iter().try_for_each(|frame_diff| {
+            writeln!(
+                f,
+                "## Frame {} `{}`\n",
+                frame_diff.frame_number, frame_diff.name
+            )?;
+            frame_diff
+                .changes
+                .iter()
+                .try_for_each(|change| writeln!(f, "- {}", change))?;
+            writeln!(f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::ObjectDataDiff;
+    use crate::{FieldChange, ObjectData};
+
+    fn object_data(weapon_strength_list: &str) -> ObjectData {
+        let object_data_str = format!(
+            "<bmp_begin>\n<bmp_end>\n<weapon_strength_list>\n{}<weapon_strength_list_end>\n\
+            <frame> 0 in_the_sky\n  pic: 0  wait: 1  next: 0\n<frame_end>\n",
+            weapon_strength_list
+        );
+        ObjectData::try_from(object_data_str.as_str()).unwrap()
+    }
+
+    #[test]
+    fn weapon_strength_entries_are_matched_by_entry_number() {
+        let before = object_data("entry: 1 normal\n  injury: 20\nentry: 2 jump\n  injury: 30\n");
+        let after = object_data(
+            "entry: 2 jump\n  injury: 35\nentry: 1 normal\n  injury: 20\nentry: 3 run\n  injury: 40\n",
+        );
+
+        let object_data_diff = ObjectDataDiff::new(&before, &after);
+
+        assert_eq!(
+            vec![
+                FieldChange {
+                    field: String::from("entry[2].injury"),
+                    before: Some(String::from("30")),
+                    after: Some(String::from("35")),
+                },
+                FieldChange {
+                    field: String::from("entry[3]"),
+                    before: None,
+                    after: Some(String::from(
+                        "name: run  dvx: 0  dvy: 0  arest: 0  vrest: 0  fall: 0  bdefend: 0  \
+                        injury: 40  effect: Normal"
+                    )),
+                },
+            ],
+            object_data_diff.weapon_strength_list
+        );
+    }
+}
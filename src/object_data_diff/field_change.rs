@@ -0,0 +1,23 @@
+use std::fmt::{self, Display};
+
+/// Change to one field, or to a whole element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Path to the field, e.g. `wait` or `itr[0].injury`.
+    pub field: String,
+    /// Value before the change, or `None` if the element was added.
+    pub before: Option<String>,
+    /// Value after the change, or `None` if the element was removed.
+    pub after: Option<String>,
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(f, "{} {} → {}", self.field, before, after),
+            (None, Some(after)) => write!(f, "{} added: {}", self.field, after),
+            (Some(before), None) => write!(f, "{} removed: {}", self.field, before),
+            (None, None) => write!(f, "{}", self.field),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{FieldChange, FrameNumber};
+
+/// Changes to a frame that exists in both object data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameDiff {
+    /// Number of the frame.
+    pub frame_number: FrameNumber,
+    /// Name of the frame after the change.
+    pub name: String,
+    /// Changed fields, in the order they are declared.
+    pub changes: Vec<FieldChange>,
+}
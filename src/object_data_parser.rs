@@ -1,13 +1,17 @@
 use std::marker::PhantomData;
 
-use pest::iterators::Pair;
+use pest::{iterators::Pair, Parser};
 use pest_derive::Parser;
 
-use crate::Error;
+use crate::{Error, ObjectData, ParseOptions, ParseOutput};
 
-#[derive(Parser)]
+/// Parses object data with the `lf2_object.pest` grammar.
+#[derive(Clone, Copy, Debug, Default, Parser)]
 #[grammar = "lf2_object.pest"]
-pub struct ObjectDataParser;
+pub struct ObjectDataParser {
+    /// Options that control how object data is parsed.
+    options: ParseOptions,
+}
 
 /// Function that processes a sub grammar rule.
 pub trait SubRuleFnTrait<'f, 'i: 'f> {
@@ -58,6 +62,47 @@ where
 }
 
 impl ObjectDataParser {
+    /// Returns a parser that follows the given options.
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: Options that control how object data is parsed.
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self { options }
+    }
+
+    /// Returns the options that this parser follows.
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Parses object data, returning it with warnings for any problems that
+    /// the options allow.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_data_str`: Object data contents, decoded if necessary.
+    pub fn object_data_parse<'i>(
+        &self,
+        object_data_str: &'i str,
+    ) -> Result<ParseOutput, Error<'i>> {
+        let mut object_data_pairs = Self::parse(Rule::Object, object_data_str)?;
+        let parse_output = object_data_pairs
+            .next()
+            .ok_or(Error::ObjectDataExpected)
+            .and_then(|pair| ObjectData::parse_with_options(pair, &self.options))?;
+
+        // We should not have another pair.
+        if object_data_pairs.peek().is_some() {
+            Err(Error::ObjectDataSurplus {
+                object_data: parse_output.object_data,
+                surplus_pairs: object_data_pairs,
+            })
+        } else {
+            Ok(parse_output)
+        }
+    }
+
     pub fn parse_as_type<'f, 'i: 'f, TBuilder, SubRule>(
         builder: TBuilder,
         pair: Pair<'i, Rule>,
@@ -71,7 +116,7 @@ impl ObjectDataParser {
         if pair.as_rule() == rule_expected {
             let pairs = pair.into_inner();
             pairs
-                .zip(subrule_fns.into_iter())
+                .zip(subrule_fns)
                 .try_fold(builder, |builder, (pair, subrule_fn)| {
                     subrule_fn.call(builder, pair)
                 })
@@ -6,7 +6,7 @@ use std::{
 };
 
 /// Object ID in `data.txt`.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ObjectId(pub usize);
 
 impl Deref for ObjectId {
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+pub use self::object_type_parse_error::ObjectTypeParseError;
+
+mod object_type_parse_error;
+
+/// Object type variants, as listed in `data.txt`.
+///
+/// See https://lf-empire.de/lf2-empire/data-changing/types
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ObjectType {
+    /// Playable and computer controlled characters.
+    #[default]
+    Character = 0,
+    /// Weapons that can be held in one hand, e.g. knives and baseball bats.
+    LightWeapon = 1,
+    /// Weapons that are carried above the head, e.g. stones and boxes.
+    HeavyWeapon = 2,
+    /// Special attacks / projectiles, e.g. energy balls and ice swords.
+    ///
+    /// Objects of this type are not affected by gravity.
+    Attack = 3,
+    /// Light weapons that are created as thrown objects, e.g. Henry's arrows.
+    ThrowWeapon = 4,
+    /// Criminals used in stage mode.
+    Criminal = 5,
+    /// Drinks such as milk and beer.
+    Drink = 6,
+}
+
+impl FromStr for ObjectType {
+    type Err = ObjectTypeParseError;
+
+    fn from_str(s: &str) -> Result<ObjectType, ObjectTypeParseError> {
+        s.parse::<u32>()
+            .map_err(ObjectTypeParseError::ParseIntError)
+            .and_then(|value| match value {
+                0 => Ok(ObjectType::Character),
+                1 => Ok(ObjectType::LightWeapon),
+                2 => Ok(ObjectType::HeavyWeapon),
+                3 => Ok(ObjectType::Attack),
+                4 => Ok(ObjectType::ThrowWeapon),
+                5 => Ok(ObjectType::Criminal),
+                6 => Ok(ObjectType::Drink),
+                value => Err(ObjectTypeParseError::InvalidValue(value)),
+            })
+    }
+}
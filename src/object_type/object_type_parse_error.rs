@@ -0,0 +1,35 @@
+use std::{fmt, fmt::Display, num::ParseIntError};
+
+/// Errors when parsing a string as an `ObjectType`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjectTypeParseError {
+    /// The string could not be parsed as a `u32`.
+    ParseIntError(ParseIntError),
+    /// The value is not recognized as a valid `ObjectType`.
+    InvalidValue(u32),
+}
+
+impl Display for ObjectTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ParseIntError(parse_int_error) => write!(f, "{}", parse_int_error),
+            Self::InvalidValue(value) => write!(
+                f,
+                "`{}` is not recognized as a valid `ObjectType` value.\n\
+                Valid values are:\n\
+                \n\
+                - 0 (Character),\n\
+                - 1 (LightWeapon),\n\
+                - 2 (HeavyWeapon),\n\
+                - 3 (Attack),\n\
+                - 4 (ThrowWeapon),\n\
+                - 5 (Criminal),\n\
+                - 6 (Drink),\n\
+                \n",
+                value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ObjectTypeParseError {}
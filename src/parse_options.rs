@@ -0,0 +1,278 @@
+pub use self::{
+    frame_limit::FrameLimit, frame_limit_parse_error::FrameLimitParseError,
+    frame_number_duplicate_mode::FrameNumberDuplicateMode, strictness::Strictness,
+};
+
+mod frame_limit;
+mod frame_limit_parse_error;
+mod frame_number_duplicate_mode;
+mod strictness;
+
+/// Configures how object data is parsed.
+///
+/// The default options behave the same as parsing with `TryFrom<&str>`:
+///
+/// * Unknown tags and duplicate frame numbers fail parsing.
+/// * Frames, elements, and tags with invalid values are skipped with a
+///   warning.
+/// * Exceeding the LF2 2.0 frame limit produces a warning.
+/// * Spans are not kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Whether tags that are not recognized within frames and elements fail
+    /// parsing, or are ignored with a warning.
+    pub tags_unknown: Strictness,
+    /// Whether values that cannot be parsed, such as unknown `kind:` or
+    /// `state:` values, fail parsing, or skip their frame or element with a
+    /// warning.
+    pub values_invalid: Strictness,
+    /// How frames that share a frame number are resolved.
+    pub frame_number_duplicates: FrameNumberDuplicateMode,
+    /// Maximum frame number and frame count.
+    pub frame_limit: FrameLimit,
+    /// Whether exceeding the frame limit fails parsing or produces a warning.
+    pub frame_limit_strictness: Strictness,
+    /// Whether to record where each frame is in the parsed text.
+    pub spans_keep: bool,
+}
+
+impl ParseOptions {
+    /// Code for diagnostics about tags that are not recognized.
+    pub const TAG_UNKNOWN: &'static str = "tag-unknown";
+    /// Code for diagnostics about values that cannot be parsed.
+    pub const VALUE_INVALID: &'static str = "value-invalid";
+    /// Code for diagnostics about frame numbers used by multiple frames.
+    pub const FRAME_NUMBER_DUPLICATE: &'static str = "frame-number-duplicate";
+
+    /// Returns options that fail parsing on any problem.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_limit`: Maximum frame number and frame count.
+    pub fn strict(frame_limit: FrameLimit) -> Self {
+        Self {
+            tags_unknown: Strictness::Strict,
+            values_invalid: Strictness::Strict,
+            frame_number_duplicates: FrameNumberDuplicateMode::Error,
+            frame_limit,
+            frame_limit_strictness: Strictness::Strict,
+            spans_keep: false,
+        }
+    }
+
+    /// Returns options that parse as much as LF2 would, reporting problems as
+    /// warnings.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame_limit`: Maximum frame number and frame count.
+    pub fn lenient(frame_limit: FrameLimit) -> Self {
+        Self {
+            tags_unknown: Strictness::Lenient,
+            values_invalid: Strictness::Lenient,
+            frame_number_duplicates: FrameNumberDuplicateMode::WarnKeepLast,
+            frame_limit,
+            frame_limit_strictness: Strictness::Lenient,
+            spans_keep: false,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            tags_unknown: Strictness::Strict,
+            values_invalid: Strictness::Lenient,
+            frame_number_duplicates: FrameNumberDuplicateMode::Error,
+            frame_limit: FrameLimit::LF2_V2_0,
+            frame_limit_strictness: Strictness::Lenient,
+            spans_keep: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use crate::{
+        Error, FrameLimit, FrameLimitParseError, FrameNumber, FrameNumberDuplicateMode,
+        ObjectDataParser, ParseOptions, ParseOutput, Severity, Strictness, Wait,
+    };
+
+    fn parse(options: ParseOptions, object_data_str: &str) -> Result<ParseOutput, Error<'_>> {
+        ObjectDataParser::with_options(options).object_data_parse(object_data_str)
+    }
+
+    fn codes(parse_output: &ParseOutput) -> Vec<&'static str> {
+        parse_output
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect()
+    }
+
+    #[test]
+    fn tags_unknown_strict_fails_and_lenient_warns() {
+        let frames =
+            "<bmp_begin>\n<bmp_end>\n<frame> 0 standing\n  pic: 0  foo: 3  wait: 2\n<frame_end>\n";
+        let options = ParseOptions::default();
+
+        let result = parse(options, frames);
+        assert!(matches!(result, Err(Error::TagUnknown { .. })));
+
+        let options = ParseOptions {
+            tags_unknown: Strictness::Lenient,
+            ..options
+        };
+        let parse_output = parse(options, frames).unwrap();
+        assert_eq!(vec![ParseOptions::TAG_UNKNOWN], codes(&parse_output));
+        assert_eq!(
+            Wait(NonZeroU32::new(2).unwrap()),
+            parse_output.object_data.frames[0].wait
+        );
+    }
+
+    #[test]
+    fn values_invalid_in_known_tag_strict_fails_and_lenient_warns() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 0 standing\n  pic: 0  state: abc  wait: x3\n<frame_end>\n";
+        let options = ParseOptions {
+            values_invalid: Strictness::Strict,
+            ..ParseOptions::default()
+        };
+
+        let result = parse(options, frames);
+        assert!(matches!(result, Err(Error::TagValueInvalid { .. })));
+
+        let options = ParseOptions {
+            values_invalid: Strictness::Lenient,
+            ..options
+        };
+        let parse_output = parse(options, frames).unwrap();
+        assert_eq!(
+            vec![ParseOptions::VALUE_INVALID, ParseOptions::VALUE_INVALID],
+            codes(&parse_output)
+        );
+        assert_eq!(1, parse_output.object_data.frames.len());
+    }
+
+    #[test]
+    fn values_invalid_in_element_lenient_skips_element() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 0 standing\n  pic: 0\n  itr:\n    kind: 99\n  itr_end:\n<frame_end>\n";
+
+        let result = parse(ParseOptions::strict(FrameLimit::LF2_V2_0), frames);
+        assert!(result.is_err());
+
+        let parse_output = parse(ParseOptions::default(), frames).unwrap();
+        assert_eq!(vec![ParseOptions::VALUE_INVALID], codes(&parse_output));
+        assert!(parse_output.object_data.frames[0].elements.is_empty());
+    }
+
+    #[test]
+    fn frame_number_duplicates_error_or_keep_first_or_last() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 0 first\n  pic: 0\n<frame_end>\n\
+            <frame> 0 last\n  pic: 1\n<frame_end>\n";
+        let options_with = |frame_number_duplicates| ParseOptions {
+            frame_number_duplicates,
+            ..ParseOptions::default()
+        };
+
+        let result = parse(options_with(FrameNumberDuplicateMode::Error), frames);
+        match result {
+            Err(Error::FrameNumberNonUnique { diagnostic, .. }) => {
+                assert_eq!(ParseOptions::FRAME_NUMBER_DUPLICATE, diagnostic.code);
+                assert_eq!(Severity::Error, diagnostic.severity);
+                assert_eq!(
+                    "Frame numbers are used by multiple frames: `0` at lines 3, 6.",
+                    diagnostic.message
+                );
+            }
+            result => panic!("Expected `FrameNumberNonUnique`, got: {:?}", result),
+        }
+
+        [
+            (FrameNumberDuplicateMode::WarnKeepFirst, "first"),
+            (FrameNumberDuplicateMode::WarnKeepLast, "last"),
+        ]
+        .iter()
+        .for_each(|&(mode, name)| {
+            let parse_output = parse(options_with(mode), frames).unwrap();
+            let frames = &parse_output.object_data.frames;
+            assert_eq!(
+                vec![ParseOptions::FRAME_NUMBER_DUPLICATE],
+                codes(&parse_output)
+            );
+            assert_eq!(1, frames.len());
+            assert_eq!(name, frames.get(FrameNumber(0)).unwrap().name);
+        });
+    }
+
+    #[test]
+    fn frame_number_limit_strict_fails_and_lenient_warns() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 400 extended\n  pic: 0\n<frame_end>\n";
+
+        let result = parse(ParseOptions::strict(FrameLimit::LF2_V2_0), frames);
+        assert!(matches!(result, Err(Error::FrameNumberExceeded { .. })));
+
+        let parse_output = parse(ParseOptions::lenient(FrameLimit::LF2_V2_0), frames).unwrap();
+        assert_eq!(
+            vec![FrameLimit::FRAME_NUMBER_EXCEEDED],
+            codes(&parse_output)
+        );
+
+        let parse_output = parse(ParseOptions::strict(FrameLimit::EXTENDED), frames).unwrap();
+        assert!(parse_output.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn frame_limit_profiles_parse_by_name() {
+        assert_eq!(Ok(FrameLimit::LF2_V2_0), "2.0".parse::<FrameLimit>());
+        assert_eq!(Ok(FrameLimit::LF2_V2_0A), "2.0A".parse::<FrameLimit>());
+        assert_eq!(Ok(FrameLimit::EXTENDED), "extended".parse::<FrameLimit>());
+        assert_eq!(
+            Err(FrameLimitParseError(String::from("1.9"))),
+            "1.9".parse::<FrameLimit>()
+        );
+    }
+
+    #[test]
+    fn frame_count_limit_strict_fails_and_lenient_warns() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 0 a\n  pic: 0\n<frame_end>\n<frame> 1 b\n  pic: 0\n<frame_end>\n";
+        let frame_limit = FrameLimit {
+            frame_number_max: FrameNumber(399),
+            frame_count_max: 1,
+        };
+
+        let result = parse(ParseOptions::strict(frame_limit), frames);
+        assert!(matches!(result, Err(Error::FrameCountExceeded { .. })));
+
+        let parse_output = parse(ParseOptions::lenient(frame_limit), frames).unwrap();
+        assert_eq!(vec![FrameLimit::FRAME_COUNT_EXCEEDED], codes(&parse_output));
+    }
+
+    #[test]
+    fn spans_keep_records_frame_lines() {
+        let frames = "<bmp_begin>\n<bmp_end>\n<frame> 0 a\n  pic: 0\n<frame_end>\n<frame> 1 b\n  pic: 0\n<frame_end>\n";
+
+        let parse_output = parse(ParseOptions::default(), frames).unwrap();
+        assert!(parse_output.frame_spans.is_empty());
+
+        let options = ParseOptions {
+            spans_keep: true,
+            ..ParseOptions::default()
+        };
+        let parse_output = parse(options, frames).unwrap();
+        let lines = parse_output
+            .frame_spans
+            .iter()
+            .map(|frame_span| {
+                (
+                    frame_span.frame_number,
+                    frame_span.line_start,
+                    frame_span.line_end,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(FrameNumber(0), 3, 5), (FrameNumber(1), 6, 8)], lines);
+    }
+}
@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use crate::{FrameLimitParseError, FrameNumber};
+
+/// Maximum frame number and number of frames that an object may have.
+///
+/// LF2 stores frames in a fixed size table indexed by frame number, so both
+/// limits come from the executable. Exe modifications that enlarge the table
+/// are supported by choosing a larger limit.
+///
+/// Named limits are listed in [`FrameLimit::PROFILES`], and can be looked up
+/// by name with [`str::parse`]. Only two distinct limits are provided: the
+/// vanilla table, and the largest table that frame numbers can address. Exes
+/// whose table lies in between can construct a `FrameLimit` with their own
+/// values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameLimit {
+    /// Largest frame number that may be used.
+    pub frame_number_max: FrameNumber,
+    /// Maximum number of frames that may be defined.
+    pub frame_count_max: usize,
+}
+
+impl FrameLimit {
+    /// Code for diagnostics about frame numbers over the limit.
+    pub const FRAME_NUMBER_EXCEEDED: &'static str = "frame-number-exceeded";
+    /// Code for diagnostics about frame counts over the limit.
+    pub const FRAME_COUNT_EXCEEDED: &'static str = "frame-count-exceeded";
+
+    /// Limits of LF2 version 2.0: frames 0 to 399.
+    pub const LF2_V2_0: Self = Self {
+        frame_number_max: FrameNumber(399),
+        frame_count_max: 400,
+    };
+
+    /// Limits of LF2 version 2.0a: frames 0 to 399.
+    ///
+    /// Version 2.0a did not change the frame table, so this is the same as
+    /// [`FrameLimit::LF2_V2_0`]. It exists so that tools can name the version
+    /// they target.
+    pub const LF2_V2_0A: Self = Self::LF2_V2_0;
+
+    /// Limits for any exe modification that enlarges the frame table.
+    ///
+    /// This is the only extended limit, as it is the largest that frame
+    /// numbers allow, and accepts data for every enlarged table.
+    /// Frame numbers stop below 999, as `next: 999` and `next: 1000` switch to
+    /// standing and delete the object instead of switching to those frames.
+    pub const EXTENDED: Self = Self {
+        frame_number_max: FrameNumber(998),
+        frame_count_max: 999,
+    };
+
+    /// Named limits, in the order that tools should list them.
+    ///
+    /// Each name is accepted by `FrameLimit`'s `FromStr` implementation.
+    pub const PROFILES: &'static [(&'static str, Self)] = &[
+        ("2.0", Self::LF2_V2_0),
+        ("2.0a", Self::LF2_V2_0A),
+        ("extended", Self::EXTENDED),
+    ];
+}
+
+impl FromStr for FrameLimit {
+    type Err = FrameLimitParseError;
+
+    fn from_str(s: &str) -> Result<FrameLimit, FrameLimitParseError> {
+        Self::PROFILES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|(_, frame_limit)| *frame_limit)
+            .ok_or_else(|| FrameLimitParseError(s.to_string()))
+    }
+}
@@ -0,0 +1,28 @@
+use std::{fmt, fmt::Display};
+
+use crate::FrameLimit;
+
+/// The string is not recognized as a `FrameLimit` profile name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameLimitParseError(pub String);
+
+impl Display for FrameLimitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid frame limit profile. Valid profiles are:\n\n",
+            self.0
+        )?;
+        FrameLimit::PROFILES
+            .iter()
+            .try_for_each(|(name, frame_limit)| {
+                writeln!(
+                    f,
+                    "* {}: frames 0 to {}",
+                    name, frame_limit.frame_number_max
+                )
+            })
+    }
+}
+
+impl std::error::Error for FrameLimitParseError {}
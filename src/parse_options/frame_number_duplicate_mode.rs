@@ -0,0 +1,13 @@
+/// How frames that share a frame number are resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameNumberDuplicateMode {
+    /// Fail parsing, listing every duplicated frame number.
+    Error,
+    /// Keep the first frame with each number, and report a warning.
+    WarnKeepFirst,
+    /// Keep the last frame with each number, and report a warning.
+    ///
+    /// This matches LF2, which loads every frame into its slot, so later
+    /// frames overwrite earlier ones.
+    WarnKeepLast,
+}
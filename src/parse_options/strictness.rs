@@ -0,0 +1,8 @@
+/// How the parser treats data that it can read, but is not valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strictness {
+    /// Fail parsing with an error.
+    Strict,
+    /// Continue parsing, and report a warning.
+    Lenient,
+}
@@ -0,0 +1,18 @@
+use crate::{Diagnostic, ObjectData};
+
+pub use self::frame_span::FrameSpan;
+
+mod frame_span;
+
+/// Result of parsing object data with `ObjectDataParser::object_data_parse`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseOutput {
+    /// The parsed object data.
+    pub object_data: ObjectData,
+    /// Warnings for problems that the `ParseOptions` allow.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Where each frame is in the parsed text, in file order.
+    ///
+    /// This is empty unless `ParseOptions::spans_keep` is set.
+    pub frame_spans: Vec<FrameSpan>,
+}
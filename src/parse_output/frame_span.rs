@@ -0,0 +1,28 @@
+use std::ops::Range;
+
+use crate::FrameNumber;
+
+/// Location of a frame in the parsed text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameSpan {
+    /// Number of the frame.
+    pub frame_number: FrameNumber,
+    /// Byte range of the frame, from `<frame>` to `<frame_end>`.
+    pub byte_range: Range<usize>,
+    /// Line that the frame begins on, starting from 1.
+    pub line_start: usize,
+    /// Line that the frame ends on, starting from 1.
+    pub line_end: usize,
+}
+
+impl FrameSpan {
+    /// Returns a `FrameSpan` for a frame `Pair`.
+    pub(crate) fn new(frame_number: FrameNumber, span: pest::Span<'_>) -> Self {
+        Self {
+            frame_number,
+            byte_range: span.start()..span.end(),
+            line_start: span.start_pos().line_col().0,
+            line_end: span.end_pos().line_col().0,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Rendering of frames for debugging object data.
+
+pub(crate) use self::{canvas::Canvas, font::Font, png::Png};
+
+pub use self::{
+    animation::Animation, animation_format::AnimationFormat,
+    animation_format_parse_error::AnimationFormatParseError, animation_frame::AnimationFrame,
+    frame_renderer::FrameRenderer, render_error::RenderError,
+};
+
+mod animation;
+mod animation_format;
+mod animation_format_parse_error;
+mod animation_frame;
+mod canvas;
+mod font;
+mod frame_renderer;
+mod png;
+mod render_error;
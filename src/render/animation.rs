@@ -0,0 +1,162 @@
+use std::{convert::TryFrom, fs::File, io::BufWriter, path::Path};
+
+use crate::{AnimationFormat, AnimationFrame, RenderError};
+
+/// Rendered images of a chain of frames.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Animation {
+    /// Images in the order they are shown.
+    pub frames: Vec<AnimationFrame>,
+}
+
+impl Animation {
+    /// Number of ticks (TU) that LF2 runs per second.
+    pub const TICKS_PER_SECOND: u32 = 30;
+
+    /// Writes the animation to a file, looping forever.
+    pub fn write(&self, path: &Path, format: AnimationFormat) -> Result<(), RenderError> {
+        let file = File::create(path).map_err(|io_error| RenderError::FileCreateError {
+            path: path.to_path_buf(),
+            io_error,
+        })?;
+        let writer = BufWriter::new(file);
+
+        match format {
+            AnimationFormat::Gif => self.gif_write(path, writer),
+            AnimationFormat::Apng => self.apng_write(path, writer),
+        }
+    }
+
+    fn gif_write(&self, path: &Path, writer: BufWriter<File>) -> Result<(), RenderError> {
+        let encode_error = |gif_error| RenderError::GifEncodeError {
+            path: path.to_path_buf(),
+            gif_error,
+        };
+        let (width, height) = self.dimensions();
+        let width = Self::u16_check(path, "width", u64::from(width))?;
+        let height = Self::u16_check(path, "height", u64::from(height))?;
+        let mut encoder = gif::Encoder::new(writer, width, height, &[]).map_err(encode_error)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(encode_error)?;
+
+        // GIF delays are in hundredths of a second, so each delay is rounded
+        // from the elapsed time to avoid accumulating rounding errors.
+        let ticks_per_second = u64::from(Self::TICKS_PER_SECOND);
+        let centis = |ticks: u64| (ticks * 100 + ticks_per_second / 2) / ticks_per_second;
+        self.frames
+            .iter()
+            .try_fold(0, |ticks_elapsed, animation_frame| {
+                let ticks_end = ticks_elapsed + u64::from(animation_frame.ticks);
+                let delay = centis(ticks_end) - centis(ticks_elapsed);
+                let delay = Self::u16_check(path, "frame delay", delay)?;
+                let mut pixels = animation_frame.image.pixels.clone();
+                let mut frame = gif::Frame::from_rgba_speed(
+                    width,
+                    height,
+                    &mut pixels,
+                    Self::GIF_QUANTIZE_SPEED,
+                );
+                frame.delay = delay;
+                frame.dispose = gif::DisposalMethod::Background;
+                encoder.write_frame(&frame).map_err(encode_error)?;
+
+                Ok(ticks_end)
+            })
+            .map(|_| ())
+    }
+
+    fn apng_write(&self, path: &Path, writer: BufWriter<File>) -> Result<(), RenderError> {
+        let encode_error = |png_error| RenderError::PngEncodeError {
+            path: path.to_path_buf(),
+            png_error,
+        };
+        let (width, height) = self.dimensions();
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .set_animated(self.frames.len() as u32, 0)
+            .map_err(encode_error)?;
+        let mut writer = encoder.write_header().map_err(encode_error)?;
+        writer
+            .set_blend_op(png::BlendOp::Source)
+            .map_err(encode_error)?;
+
+        self.frames.iter().try_for_each(|animation_frame| {
+            let delay = Self::u16_check(path, "frame delay", u64::from(animation_frame.ticks))?;
+            writer
+                .set_frame_delay(delay, Self::TICKS_PER_SECOND as u16)
+                .map_err(encode_error)?;
+            writer
+                .write_image_data(&animation_frame.image.pixels)
+                .map_err(encode_error)
+        })?;
+        writer.finish().map_err(encode_error)
+    }
+
+    fn dimensions(&self) -> (u32, u32) {
+        self.frames
+            .first()
+            .map(|animation_frame| (animation_frame.image.width, animation_frame.image.height))
+            .unwrap_or((0, 0))
+    }
+
+    /// Returns the value as a `u16`, or an error if it does not fit.
+    fn u16_check(path: &Path, field: &'static str, value: u64) -> Result<u16, RenderError> {
+        u16::try_from(value).map_err(|_| RenderError::ValueTooLarge {
+            path: path.to_path_buf(),
+            field,
+            value,
+        })
+    }
+
+    /// Speed of colour quantization for GIFs, from 1 (best) to 30 (fastest).
+    const GIF_QUANTIZE_SPEED: i32 = 10;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process, time::SystemTime};
+
+    use super::Animation;
+    use crate::{AnimationFormat, AnimationFrame, FrameNumber, RenderError, SpriteImage};
+
+    fn animation(width: u32, ticks: u32) -> Animation {
+        Animation {
+            frames: vec![AnimationFrame {
+                frame_number: FrameNumber(0),
+                image: SpriteImage::new(width, 1),
+                ticks,
+            }],
+        }
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_fit_u16() {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let dir = env::temp_dir().join(format!(
+            "lf2_parse_animation_too_large_{}_{}",
+            process::id(),
+            nanos
+        ));
+        fs::create_dir(&dir).unwrap();
+        let path = dir.join("animation");
+        [
+            (animation(70_000, 1), AnimationFormat::Gif, "width"),
+            (animation(1, 70_000), AnimationFormat::Gif, "frame delay"),
+            (animation(1, 70_000), AnimationFormat::Apng, "frame delay"),
+        ]
+        .iter()
+        .for_each(|(animation, format, field_expected)| {
+            match animation.write(&path, *format) {
+                Err(RenderError::ValueTooLarge { field, .. }) => assert_eq!(*field_expected, field),
+                result => panic!("Expected `ValueTooLarge`, got: {:?}", result),
+            }
+        });
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,36 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::AnimationFormatParseError;
+
+/// File formats that animations can be written as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationFormat {
+    /// Animated GIF, with colours reduced to a 256 colour palette.
+    Gif,
+    /// Animated PNG, with exact frame delays.
+    Apng,
+}
+
+impl Display for AnimationFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Gif => write!(f, "gif"),
+            Self::Apng => write!(f, "apng"),
+        }
+    }
+}
+
+impl FromStr for AnimationFormat {
+    type Err = AnimationFormatParseError;
+
+    fn from_str(s: &str) -> Result<AnimationFormat, AnimationFormatParseError> {
+        match s {
+            "gif" => Ok(Self::Gif),
+            "apng" | "png" => Ok(Self::Apng),
+            _ => Err(AnimationFormatParseError(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use std::{fmt, fmt::Display};
+
+/// The string is not recognized as an `AnimationFormat`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnimationFormatParseError(pub String);
+
+impl Display for AnimationFormatParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid animation format. Expected `gif` or `apng`.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for AnimationFormatParseError {}
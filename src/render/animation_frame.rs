@@ -0,0 +1,12 @@
+use crate::{FrameNumber, SpriteImage};
+
+/// Image of one frame in an animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationFrame {
+    /// Frame that was rendered.
+    pub frame_number: FrameNumber,
+    /// Rendered image, the same size as the other images in the animation.
+    pub image: SpriteImage,
+    /// Number of ticks (TU) that the frame is shown for.
+    pub ticks: u32,
+}
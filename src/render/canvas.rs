@@ -0,0 +1,121 @@
+use std::ops::Range;
+
+use crate::SpriteImage;
+
+/// Image that can be drawn on using coordinates with an arbitrary origin.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Canvas {
+    /// Image being drawn on.
+    pub(crate) image: SpriteImage,
+    /// Coordinate drawn at the image's left edge.
+    pub(crate) x_min: i64,
+    /// Coordinate drawn at the image's top edge.
+    pub(crate) y_min: i64,
+}
+
+impl Canvas {
+    /// Alpha of the inside of rectangles.
+    pub(crate) const FILL_ALPHA: u8 = 48;
+
+    /// Returns a transparent canvas covering the given bounds.
+    ///
+    /// `x_max` and `y_max` are exclusive.
+    pub(crate) fn new(x_min: i64, y_min: i64, x_max: i64, y_max: i64) -> Self {
+        let width = (x_max - x_min).max(0) as u32;
+        let height = (y_max - y_min).max(0) as u32;
+        Self {
+            image: SpriteImage::new(width, height),
+            x_min,
+            y_min,
+        }
+    }
+
+    /// Returns this canvas mirrored left to right about the `x = 0` line.
+    pub(crate) fn flipped(&self) -> Canvas {
+        Self {
+            image: self.image.flipped(),
+            x_min: -(self.x_min + i64::from(self.image.width)),
+            y_min: self.y_min,
+        }
+    }
+
+    /// Blends a pixel over the existing pixel at the given coordinates.
+    pub(crate) fn pixel_blend(&mut self, x: i64, y: i64, rgba: [u8; 4]) {
+        let (x, y) = (x - self.x_min, y - self.y_min);
+        if x < 0 || y < 0 {
+            return;
+        }
+        let (x, y) = (x as u32, y as u32);
+        if let Some(existing) = self.image.pixel(x, y) {
+            let alpha = u32::from(rgba[3]);
+            let alpha_existing = u32::from(existing[3]) * (255 - alpha) / 255;
+            let alpha_out = alpha + alpha_existing;
+            if alpha_out == 0 {
+                return;
+            }
+            let channel = |index: usize| {
+                ((u32::from(rgba[index]) * alpha + u32::from(existing[index]) * alpha_existing)
+                    / alpha_out) as u8
+            };
+            self.image
+                .pixel_set(x, y, [channel(0), channel(1), channel(2), alpha_out as u8]);
+        }
+    }
+
+    /// Draws an image with its top left corner at the given coordinates.
+    pub(crate) fn image_draw(&mut self, x: i64, y: i64, image: &SpriteImage) {
+        (0..image.height).for_each(|y_offset| {
+            (0..image.width).for_each(|x_offset| {
+                if let Some(rgba) = image.pixel(x_offset, y_offset) {
+                    self.pixel_blend(x + i64::from(x_offset), y + i64::from(y_offset), rgba);
+                }
+            })
+        });
+    }
+
+    /// Draws a rectangle outline, with a translucent fill.
+    pub(crate) fn rect_draw(&mut self, x: i64, y: i64, w: i64, h: i64, rgb: [u8; 3]) {
+        let fill = [rgb[0], rgb[1], rgb[2], Self::FILL_ALPHA];
+        let line = [rgb[0], rgb[1], rgb[2], 255];
+        let (x_range, y_range) = self.clip(x, y, w, h);
+        y_range.for_each(|y_pixel| {
+            x_range.clone().for_each(|x_pixel| {
+                let edge =
+                    x_pixel == x || x_pixel == x + w - 1 || y_pixel == y || y_pixel == y + h - 1;
+                self.pixel_blend(x_pixel, y_pixel, if edge { line } else { fill });
+            })
+        });
+    }
+
+    /// Draws an opaque filled rectangle.
+    pub(crate) fn rect_fill(&mut self, x: i64, y: i64, w: i64, h: i64, rgb: [u8; 3]) {
+        let rgba = [rgb[0], rgb[1], rgb[2], 255];
+        let (x_range, y_range) = self.clip(x, y, w, h);
+        y_range.for_each(|y_pixel| {
+            x_range
+                .clone()
+                .for_each(|x_pixel| self.pixel_blend(x_pixel, y_pixel, rgba))
+        });
+    }
+
+    /// Returns the coordinates of a rectangle that are within the canvas.
+    fn clip(&self, x: i64, y: i64, w: i64, h: i64) -> (Range<i64>, Range<i64>) {
+        let x_max = self.x_min + i64::from(self.image.width);
+        let y_max = self.y_min + i64::from(self.image.height);
+        (
+            x.max(self.x_min)..(x + w).min(x_max),
+            y.max(self.y_min)..(y + h).min(y_max),
+        )
+    }
+
+    /// Draws a `+` shaped marker centered on the given coordinates.
+    pub(crate) fn marker_draw(&mut self, x: i64, y: i64, radius: i64, rgb: [u8; 3]) {
+        let line = [rgb[0], rgb[1], rgb[2], 255];
+        (-radius..=radius).for_each(|offset| {
+            self.pixel_blend(x + offset, y, line);
+            if offset != 0 {
+                self.pixel_blend(x, y + offset, line);
+            }
+        });
+    }
+}
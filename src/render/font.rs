@@ -0,0 +1,104 @@
+use crate::render::Canvas;
+
+/// Small bitmap font for labelling rendered images.
+///
+/// Glyphs are 3 by 5 pixels. Lowercase letters are drawn as uppercase, and
+/// characters without a glyph are drawn as `?`.
+#[derive(Debug)]
+pub(crate) struct Font;
+
+impl Font {
+    /// Width of each glyph.
+    pub(crate) const GLYPH_WIDTH: i64 = 3;
+    /// Height of each glyph.
+    pub(crate) const GLYPH_HEIGHT: i64 = 5;
+    /// Horizontal distance between the start of consecutive glyphs.
+    pub(crate) const ADVANCE: i64 = Self::GLYPH_WIDTH + 1;
+    /// Vertical distance between the top of consecutive lines.
+    pub(crate) const LINE_HEIGHT: i64 = Self::GLYPH_HEIGHT + 2;
+
+    /// Returns the width of a line of text.
+    pub(crate) fn text_width(text: &str) -> i64 {
+        (text.chars().count() as i64 * Self::ADVANCE - 1).max(0)
+    }
+
+    /// Draws a line of text with its top left corner at the given coordinates.
+    ///
+    /// Characters that would extend past `width` are not drawn.
+    pub(crate) fn text_draw(
+        canvas: &mut Canvas,
+        x: i64,
+        y: i64,
+        width: i64,
+        text: &str,
+        rgb: [u8; 3],
+    ) {
+        let rgba = [rgb[0], rgb[1], rgb[2], 255];
+        let glyph_count = (width + 1) / Self::ADVANCE;
+        text.chars()
+            .take(glyph_count.max(0) as usize)
+            .enumerate()
+            .for_each(|(index, c)| {
+                let glyph_x = x + index as i64 * Self::ADVANCE;
+                Self::glyph(c).iter().enumerate().for_each(|(row, bits)| {
+                    (0..Self::GLYPH_WIDTH).for_each(|column| {
+                        if bits & (0b100 >> column) != 0 {
+                            canvas.pixel_blend(glyph_x + column, y + row as i64, rgba);
+                        }
+                    })
+                });
+            });
+    }
+
+    /// Returns the rows of a glyph, with the leftmost pixel in the highest bit.
+    fn glyph(c: char) -> [u8; 5] {
+        match c.to_ascii_uppercase() {
+            'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+            'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+            'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+            'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+            'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+            'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+            'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+            'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+            'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+            'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+            'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+            'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+            'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+            'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+            'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+            'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+            'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+            'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+            'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+            'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+            'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+            'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+            'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+            'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+            'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+            'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+            '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+            '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+            '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+            '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+            '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+            '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+            '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+            '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+            '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+            '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+            ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+            '_' => [0b000, 0b000, 0b000, 0b000, 0b111],
+            '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+            '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+            ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+            '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+            '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+            '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+            ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+            _ => [0b110, 0b001, 0b010, 0b000, 0b010],
+        }
+    }
+}
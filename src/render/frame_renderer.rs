@@ -0,0 +1,580 @@
+use std::path::Path;
+
+use crate::{
+    render::{Canvas, Font, Png},
+    Animation, AnimationFrame, Element, Frame, FrameNumber, Frames, ItrKind, RenderError,
+    SpriteImage, Sprites,
+};
+
+/// Renders frames with their elements drawn over the sprite.
+#[derive(Clone, Copy, Debug)]
+pub struct FrameRenderer<'s> {
+    /// Sprite sheets of the object being rendered.
+    pub sprites: &'s Sprites,
+    /// Whether to draw frame elements and the frame center.
+    pub overlay: bool,
+}
+
+impl<'s> FrameRenderer<'s> {
+    /// Colour of `bdy` boxes.
+    pub const COLOR_BDY: [u8; 3] = [0, 128, 255];
+    /// Colour of `bpoint` markers.
+    pub const COLOR_B_POINT: [u8; 3] = [160, 0, 0];
+    /// Colour of `cpoint` markers.
+    pub const COLOR_C_POINT: [u8; 3] = [255, 255, 0];
+    /// Colour of `wpoint` markers.
+    pub const COLOR_W_POINT: [u8; 3] = [0, 255, 255];
+    /// Colour of `opoint` markers.
+    pub const COLOR_O_POINT: [u8; 3] = [255, 255, 255];
+    /// Colour of the `centerx` / `centery` marker.
+    pub const COLOR_CENTER: [u8; 3] = [255, 0, 255];
+    /// Half the length of the lines of point markers.
+    pub const MARKER_RADIUS: i64 = 3;
+    /// Distance beyond the sprite that elements are drawn.
+    ///
+    /// Data files commonly disable elements by moving them far away, such as
+    /// `y: 2000`, so elements past this margin are clipped.
+    pub const OVERLAY_MARGIN: i64 = 32;
+
+    /// Background colour of contact sheets.
+    pub const COLOR_SHEET_BACKGROUND: [u8; 3] = [24, 24, 24];
+    /// Background colour of each frame's cell in contact sheets.
+    pub const COLOR_SHEET_CELL: [u8; 3] = [48, 48, 48];
+    /// Colour of text in contact sheets.
+    pub const COLOR_SHEET_TEXT: [u8; 3] = [224, 224, 224];
+    /// Space between cells in contact sheets.
+    const SHEET_PADDING: i64 = 6;
+    /// Maximum width that cells in contact sheets are widened to for labels.
+    const SHEET_LABEL_WIDTH_MAX: i64 = 120;
+
+    /// Returns a new `FrameRenderer`.
+    pub fn new(sprites: &'s Sprites, overlay: bool) -> Self {
+        Self { sprites, overlay }
+    }
+
+    /// Returns the colour used to draw an `itr` box of the given kind.
+    pub fn itr_color(kind: ItrKind) -> [u8; 3] {
+        match kind {
+            ItrKind::Normal => [255, 0, 0],
+            ItrKind::CatchStunned | ItrKind::CatchForce => [255, 160, 0],
+            ItrKind::WeaponPick | ItrKind::RollWeaponPick => [0, 200, 0],
+            ItrKind::Falling => [255, 96, 160],
+            ItrKind::WeaponStrength => [200, 0, 80],
+            ItrKind::SuperPunch => [255, 220, 120],
+            ItrKind::HealBall => [128, 255, 128],
+            ItrKind::ReflectiveShield | ItrKind::Wall => [160, 160, 255],
+            ItrKind::SonataOfDeath | ItrKind::SonataOfDeath2 => [128, 0, 255],
+            ItrKind::WhirlwindWind | ItrKind::WhirlwindIce => [96, 224, 255],
+        }
+    }
+
+    /// Renders a frame.
+    ///
+    /// The image covers the frame's sprite and, when overlays are enabled, all
+    /// of its elements, up to [`Self::OVERLAY_MARGIN`] beyond the sprite.
+    /// When the frame's pic is negative, the sprite and its elements are
+    /// mirrored.
+    pub fn render(&self, frame: &Frame) -> SpriteImage {
+        self.canvas(frame).image
+    }
+
+    /// Renders a frame onto a canvas whose coordinates are relative to the
+    /// frame's center.
+    pub(crate) fn canvas(&self, frame: &Frame) -> Canvas {
+        let sprite = self.sprites.pic(frame.pic);
+        let (sprite_w, sprite_h) = sprite
+            .as_ref()
+            .map(|sprite| (i64::from(sprite.width), i64::from(sprite.height)))
+            .unwrap_or((0, 0));
+        let mirror = frame.pic.facing_switch();
+        let rect_x = |x: i32, w: u32| {
+            if mirror {
+                sprite_w - i64::from(x) - i64::from(w)
+            } else {
+                i64::from(x)
+            }
+        };
+        let point_x = |x: i64| if mirror { sprite_w - x } else { x };
+
+        let center_x = point_x(frame.center_x);
+        let center_y = frame.center_y;
+
+        let mut rects = Vec::new();
+        let mut markers = vec![(
+            center_x,
+            center_y,
+            Self::MARKER_RADIUS * 2,
+            Self::COLOR_CENTER,
+        )];
+        frame.elements.iter().for_each(|element| match element {
+            Element::Bdy(bdy) => rects.push((
+                rect_x(bdy.x, bdy.w),
+                i64::from(bdy.y),
+                i64::from(bdy.w),
+                i64::from(bdy.h),
+                Self::COLOR_BDY,
+            )),
+            Element::Itr(itr) => rects.push((
+                rect_x(itr.x, itr.w),
+                i64::from(itr.y),
+                i64::from(itr.w),
+                i64::from(itr.h),
+                Self::itr_color(itr.kind),
+            )),
+            Element::BPoint(b_point) => markers.push((
+                point_x(i64::from(b_point.x)),
+                i64::from(b_point.y),
+                Self::MARKER_RADIUS,
+                Self::COLOR_B_POINT,
+            )),
+            Element::CPoint(c_point) => markers.push((
+                point_x(i64::from(c_point.x)),
+                i64::from(c_point.y),
+                Self::MARKER_RADIUS,
+                Self::COLOR_C_POINT,
+            )),
+            Element::WPoint(w_point) => markers.push((
+                point_x(i64::from(w_point.x)),
+                i64::from(w_point.y),
+                Self::MARKER_RADIUS,
+                Self::COLOR_W_POINT,
+            )),
+            Element::OPoint(o_point) => markers.push((
+                point_x(i64::from(o_point.x)),
+                i64::from(o_point.y),
+                Self::MARKER_RADIUS,
+                Self::COLOR_O_POINT,
+            )),
+        });
+        if !self.overlay {
+            rects.clear();
+            markers.clear();
+        }
+
+        let bounds = rects
+            .iter()
+            .map(|&(x, y, w, h, _)| (x, y, x + w, y + h))
+            .chain(
+                markers
+                    .iter()
+                    .map(|&(x, y, r, _)| (x - r, y - r, x + r + 1, y + r + 1)),
+            )
+            .fold(
+                (0, 0, sprite_w, sprite_h),
+                |(x_min, y_min, x_max, y_max), (x_0, y_0, x_1, y_1)| {
+                    (
+                        x_min.min(x_0),
+                        y_min.min(y_0),
+                        x_max.max(x_1),
+                        y_max.max(y_1),
+                    )
+                },
+            );
+        let margin = Self::OVERLAY_MARGIN;
+        let mut canvas = Canvas::new(
+            bounds.0.max(-margin),
+            bounds.1.max(-margin),
+            bounds.2.min(sprite_w + margin),
+            bounds.3.min(sprite_h + margin),
+        );
+
+        if let Some(sprite) = sprite.as_ref() {
+            canvas.image_draw(0, 0, sprite);
+        }
+        rects
+            .into_iter()
+            .for_each(|(x, y, w, h, rgb)| canvas.rect_draw(x, y, w, h, rgb));
+        markers
+            .into_iter()
+            .for_each(|(x, y, radius, rgb)| canvas.marker_draw(x, y, radius, rgb));
+
+        canvas.x_min -= center_x;
+        canvas.y_min -= center_y;
+        canvas
+    }
+
+    /// Renders the chain of `next` frames from a frame as an animation.
+    ///
+    /// Frames are aligned by their centers, and are mirrored after a negative
+    /// `next` value switches the object's facing direction. Returns `None` if
+    /// the starting frame does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `frames`: Frames of the object.
+    /// * `frame_number`: Frame that the chain begins from.
+    pub fn animation(&self, frames: &Frames, frame_number: FrameNumber) -> Option<Animation> {
+        let frame_timing = frames.timing(frame_number)?;
+        let mut facing_switched = false;
+        let canvases = frame_timing
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let frame = frames.get(entry.frame_number)?;
+                let canvas = self.canvas(frame);
+                let canvas = if facing_switched {
+                    canvas.flipped()
+                } else {
+                    canvas
+                };
+                facing_switched ^= frame.next_frame.facing_switch();
+
+                Some((entry, canvas))
+            })
+            .collect::<Vec<_>>();
+
+        let (x_min, y_min, x_max, y_max) =
+            canvases
+                .iter()
+                .fold((0, 0, 0, 0), |(x_min, y_min, x_max, y_max), (_, canvas)| {
+                    (
+                        x_min.min(canvas.x_min),
+                        y_min.min(canvas.y_min),
+                        x_max.max(canvas.x_min + i64::from(canvas.image.width)),
+                        y_max.max(canvas.y_min + i64::from(canvas.image.height)),
+                    )
+                });
+        let frames = canvases
+            .into_iter()
+            .map(|(entry, canvas)| {
+                let mut canvas_full = Canvas::new(x_min, y_min, x_max, y_max);
+                canvas_full.image_draw(canvas.x_min, canvas.y_min, &canvas.image);
+
+                AnimationFrame {
+                    frame_number: entry.frame_number,
+                    image: canvas_full.image,
+                    ticks: entry.ticks,
+                }
+            })
+            .collect::<Vec<AnimationFrame>>();
+
+        Some(Animation { frames })
+    }
+
+    /// Renders every frame into one image, for reviewing an object at a glance.
+    ///
+    /// Frames with the same name are placed on the same row, in the order the
+    /// name first appears. Each cell is labelled with the frame's number, name,
+    /// state, and wait, and frames are aligned by their centers.
+    pub fn contact_sheet(&self, frames: &Frames) -> SpriteImage {
+        let canvases = frames
+            .iter()
+            .map(|frame| {
+                let labels = [
+                    format!("{} {}", frame.number, frame.name),
+                    format!("{:?} W{}", frame.state, frame.wait),
+                ];
+                (self.canvas(frame), labels)
+            })
+            .collect::<Vec<_>>();
+
+        let mut groups = Vec::<(&str, Vec<usize>)>::new();
+        frames.iter().enumerate().for_each(|(index, frame)| {
+            match groups.iter_mut().find(|(name, _)| *name == frame.name) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((&frame.name, vec![index])),
+            }
+        });
+
+        let (x_min, y_min, x_max, y_max) =
+            canvases
+                .iter()
+                .fold((0, 0, 0, 0), |(x_min, y_min, x_max, y_max), (canvas, _)| {
+                    (
+                        x_min.min(canvas.x_min),
+                        y_min.min(canvas.y_min),
+                        x_max.max(canvas.x_min + i64::from(canvas.image.width)),
+                        y_max.max(canvas.y_min + i64::from(canvas.image.height)),
+                    )
+                });
+        let label_w = canvases
+            .iter()
+            .flat_map(|(_, labels)| labels.iter())
+            .map(|label| Font::text_width(label))
+            .max()
+            .unwrap_or(0)
+            .min(Self::SHEET_LABEL_WIDTH_MAX);
+        let padding = Self::SHEET_PADDING;
+        let image_w = x_max - x_min;
+        let image_h = y_max - y_min;
+        let cell_w = image_w.max(label_w) + 2;
+        let cell_h = image_h + 2 + 2 * Font::LINE_HEIGHT;
+        let group_h = Font::LINE_HEIGHT + cell_h + padding;
+        let column_count = groups
+            .iter()
+            .map(|(_, indices)| indices.len() as i64)
+            .max()
+            .unwrap_or(0);
+
+        let mut sheet = Canvas::new(
+            0,
+            0,
+            padding + column_count * (cell_w + padding),
+            padding + groups.len() as i64 * group_h,
+        );
+        let (sheet_w, sheet_h) = (i64::from(sheet.image.width), i64::from(sheet.image.height));
+        sheet.rect_fill(0, 0, sheet_w, sheet_h, Self::COLOR_SHEET_BACKGROUND);
+
+        groups
+            .iter()
+            .enumerate()
+            .for_each(|(group_index, (name, indices))| {
+                let group_y = padding + group_index as i64 * group_h;
+                Font::text_draw(
+                    &mut sheet,
+                    padding,
+                    group_y,
+                    sheet_w - padding,
+                    name,
+                    Self::COLOR_SHEET_TEXT,
+                );
+
+                let cell_y = group_y + Font::LINE_HEIGHT;
+                indices.iter().enumerate().for_each(|(column, &index)| {
+                    let (canvas, labels) = &canvases[index];
+                    let cell_x = padding + column as i64 * (cell_w + padding);
+                    sheet.rect_fill(cell_x, cell_y, cell_w, cell_h, Self::COLOR_SHEET_CELL);
+
+                    let image_x = cell_x + 1 + (cell_w - 2 - image_w) / 2;
+                    sheet.image_draw(
+                        image_x + canvas.x_min - x_min,
+                        cell_y + 1 + canvas.y_min - y_min,
+                        &canvas.image,
+                    );
+
+                    let text_y = cell_y + 1 + image_h + 1;
+                    labels.iter().enumerate().for_each(|(line, text)| {
+                        Font::text_draw(
+                            &mut sheet,
+                            cell_x + 1,
+                            text_y + line as i64 * Font::LINE_HEIGHT,
+                            cell_w - 2,
+                            text,
+                            Self::COLOR_SHEET_TEXT,
+                        );
+                    });
+                });
+            });
+
+        sheet.image
+    }
+
+    /// Renders a contact sheet of every frame and writes it to a PNG file.
+    pub fn contact_sheet_png(&self, frames: &Frames, path: &Path) -> Result<(), RenderError> {
+        Png::write(path, &self.contact_sheet(frames))
+    }
+
+    /// Renders a frame and writes it to a PNG file.
+    pub fn render_png(&self, frame: &Frame, path: &Path) -> Result<(), RenderError> {
+        Png::write(path, &self.render(frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::FrameRenderer;
+    use crate::{
+        render::{Canvas, Font},
+        Frame, FrameNumber, ItrKind, ObjectData, SpriteImage, SpriteSheet, Sprites,
+    };
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const GREEN: [u8; 4] = [0, 255, 0, 255];
+
+    /// Returns frames using a sheet of two 4x4 pics, and sprites for the sheet.
+    ///
+    /// Pic 0 has a red pixel at its top left and a green pixel at its bottom
+    /// right. Pic 1 has a red pixel at its top left.
+    fn object_data_and_sprites() -> (ObjectData, Sprites) {
+        let object_data_str = "<bmp_begin>\n\
+            file(0-1): sprite\\test.bmp  w: 4  h: 4  row: 2  col: 1\n\
+            <bmp_end>\n\
+            <frame> 0 plain\n  pic: 0  wait: 1  next: 0  centerx: 2  centery: 4\n<frame_end>\n\
+            <frame> 1 mirrored\n  pic: -1  wait: 1  next: 0  centerx: 1  centery: 4\n<frame_end>\n\
+            <frame> 2 boxes\n  pic: 0  wait: 1  next: 0  centerx: 2  centery: 4\n\
+            \x20  bdy:\n      kind: 0  x: 0  y: 0  w: 4  h: 4\n   bdy_end:\n\
+            \x20  itr:\n      kind: 0  x: 3  y: 1  w: 1  h: 2\n   itr_end:\n\
+            <frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+
+        let mut image = SpriteImage::new(9, 4);
+        image.pixel_set(0, 0, RED);
+        image.pixel_set(3, 3, GREEN);
+        image.pixel_set(5, 0, RED);
+        let sprites = Sprites {
+            sheets: vec![SpriteSheet {
+                sprite_file: object_data.header.sprite_files[0].clone(),
+                image,
+            }],
+        };
+
+        (object_data, sprites)
+    }
+
+    /// Returns the pixel at coordinates relative to the top left of the
+    /// frame's sprite, for a canvas whose origin is the frame's center.
+    fn pixel(canvas: &Canvas, center: (i64, i64), x: i64, y: i64) -> Option<[u8; 4]> {
+        let x = x - center.0 - canvas.x_min;
+        let y = y - center.1 - canvas.y_min;
+        if x < 0 || y < 0 {
+            return None;
+        }
+        canvas.image.pixel(x as u32, y as u32)
+    }
+
+    fn frame(object_data: &ObjectData, frame_number: usize) -> &Frame {
+        object_data.frames.get(FrameNumber(frame_number)).unwrap()
+    }
+
+    #[test]
+    fn render_keeps_transparent_pixels_without_overlay() {
+        let (object_data, sprites) = object_data_and_sprites();
+        let frame_renderer = FrameRenderer::new(&sprites, false);
+
+        let image = frame_renderer.render(frame(&object_data, 0));
+
+        assert_eq!((4, 4), (image.width, image.height));
+        assert_eq!(Some(RED), image.pixel(0, 0));
+        assert_eq!(Some(GREEN), image.pixel(3, 3));
+        assert_eq!(Some([0; 4]), image.pixel(1, 0));
+        assert_eq!(Some([0; 4]), image.pixel(2, 2));
+    }
+
+    #[test]
+    fn canvas_origin_is_frame_center() {
+        let (object_data, sprites) = object_data_and_sprites();
+        let frame_renderer = FrameRenderer::new(&sprites, false);
+
+        let canvas = frame_renderer.canvas(frame(&object_data, 0));
+        assert_eq!((-2, -4), (canvas.x_min, canvas.y_min));
+
+        // Negative pics mirror the sprite and the center.
+        let canvas = frame_renderer.canvas(frame(&object_data, 1));
+        assert_eq!((-3, -4), (canvas.x_min, canvas.y_min));
+        assert_eq!(Some(RED), canvas.image.pixel(3, 0));
+        assert_eq!(Some([0; 4]), canvas.image.pixel(0, 0));
+    }
+
+    #[test]
+    fn overlay_draws_elements_and_center_over_sprite() {
+        let (object_data, sprites) = object_data_and_sprites();
+        let frame_renderer = FrameRenderer::new(&sprites, true);
+
+        let canvas = frame_renderer.canvas(frame(&object_data, 2));
+        let center = (2, 4);
+
+        // The center marker widens the image past the sprite.
+        let radius = FrameRenderer::MARKER_RADIUS * 2;
+        assert_eq!((-radius, -radius), (canvas.x_min, canvas.y_min));
+        let [r, g, b] = FrameRenderer::COLOR_CENTER;
+        assert_eq!(Some([r, g, b, 255]), pixel(&canvas, center, 2, 4));
+
+        let [r, g, b] = FrameRenderer::COLOR_BDY;
+        assert_eq!(Some([r, g, b, 255]), pixel(&canvas, center, 0, 0));
+        assert_eq!(
+            Some([r, g, b, Canvas::FILL_ALPHA]),
+            pixel(&canvas, center, 1, 1)
+        );
+
+        let [r, g, b] = FrameRenderer::itr_color(ItrKind::Normal);
+        assert_eq!(Some([r, g, b, 255]), pixel(&canvas, center, 3, 1));
+        assert_eq!(Some([r, g, b, 255]), pixel(&canvas, center, 3, 2));
+    }
+
+    #[test]
+    fn contact_sheet_places_frames_in_rows_by_name_with_labels() {
+        let (object_data, sprites) = object_data_and_sprites();
+        let frames = [0, 2, 0]
+            .iter()
+            .enumerate()
+            .map(|(number, frame_number)| Frame {
+                number: FrameNumber(number),
+                name: String::from(if number == 1 { "kick" } else { "punch" }),
+                ..frame(&object_data, *frame_number).clone()
+            })
+            .collect();
+        let frame_renderer = FrameRenderer::new(&sprites, false);
+
+        let sheet = frame_renderer.contact_sheet(&frames);
+
+        let labels = |frame_number: usize| {
+            let frame = frames.get(FrameNumber(frame_number)).unwrap();
+            [
+                format!("{} {}", frame.number, frame.name),
+                format!("{:?} W{}", frame.state, frame.wait),
+            ]
+        };
+        let label_w = (0..3)
+            .flat_map(labels)
+            .map(|label| Font::text_width(&label))
+            .max()
+            .unwrap();
+        let padding = FrameRenderer::SHEET_PADDING;
+        let cell_w = label_w.max(4) + 2;
+        let cell_h = 4 + 2 + 2 * Font::LINE_HEIGHT;
+        let group_h = Font::LINE_HEIGHT + cell_h + padding;
+
+        // `punch` frames share the first row, `kick` is on the second.
+        assert_eq!(
+            (
+                (padding + 2 * (cell_w + padding)) as u32,
+                (padding + 2 * group_h) as u32
+            ),
+            (sheet.width, sheet.height)
+        );
+        let [r, g, b] = FrameRenderer::COLOR_SHEET_CELL;
+        let cell_color = Some([r, g, b, 255]);
+        let [r, g, b] = FrameRenderer::COLOR_SHEET_BACKGROUND;
+        let background_color = Some([r, g, b, 255]);
+        [(0, 0, 0), (1, 0, 2), (0, 1, 1)]
+            .iter()
+            .for_each(|&(column, row, frame_number)| {
+                let cell_x = padding + column * (cell_w + padding);
+                let cell_y = padding + row * group_h + Font::LINE_HEIGHT;
+                let pixel = |x: i64, y: i64| sheet.pixel(x as u32, y as u32);
+                assert_eq!(cell_color, pixel(cell_x, cell_y), "frame {}", frame_number);
+                assert_eq!(background_color, pixel(cell_x - 1, cell_y));
+
+                // The sprite is centered in the cell, below its top border.
+                let image_x = cell_x + 1 + (cell_w - 6) / 2;
+                assert_eq!(Some(RED), pixel(image_x, cell_y + 1));
+
+                // Labels are drawn below the sprite.
+                let text_y = cell_y + 1 + 4 + 1;
+                let mut label_canvas = Canvas::new(0, 0, cell_w - 2, 2 * Font::LINE_HEIGHT);
+                label_canvas.rect_fill(
+                    0,
+                    0,
+                    cell_w - 2,
+                    2 * Font::LINE_HEIGHT,
+                    FrameRenderer::COLOR_SHEET_CELL,
+                );
+                labels(frame_number)
+                    .iter()
+                    .enumerate()
+                    .for_each(|(line, text)| {
+                        Font::text_draw(
+                            &mut label_canvas,
+                            0,
+                            line as i64 * Font::LINE_HEIGHT,
+                            cell_w - 2,
+                            text,
+                            FrameRenderer::COLOR_SHEET_TEXT,
+                        );
+                    });
+                assert_eq!(
+                    label_canvas.image,
+                    sheet.crop(
+                        (cell_x + 1) as u32,
+                        text_y as u32,
+                        (cell_w - 2) as u32,
+                        (2 * Font::LINE_HEIGHT) as u32
+                    ),
+                    "frame {}",
+                    frame_number
+                );
+            });
+    }
+}
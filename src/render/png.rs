@@ -0,0 +1,30 @@
+use std::{fs::File, io::BufWriter, path::Path};
+
+use crate::{RenderError, SpriteImage};
+
+/// Encodes images as PNG files.
+#[derive(Debug)]
+pub(crate) struct Png;
+
+impl Png {
+    /// Writes an image to a PNG file.
+    pub(crate) fn write(path: &Path, image: &SpriteImage) -> Result<(), RenderError> {
+        let file = File::create(path).map_err(|io_error| RenderError::FileCreateError {
+            path: path.to_path_buf(),
+            io_error,
+        })?;
+        let encode_error = |png_error| RenderError::PngEncodeError {
+            path: path.to_path_buf(),
+            png_error,
+        };
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), image.width, image.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(encode_error)?;
+        writer
+            .write_image_data(&image.pixels)
+            .map_err(encode_error)?;
+        writer.finish().map_err(encode_error)
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt, fmt::Display, io, path::PathBuf};
+
+/// Errors when writing rendered images and animations.
+#[derive(Debug)]
+pub enum RenderError {
+    /// Failed to create an output file.
+    FileCreateError {
+        /// Path to the output file.
+        path: PathBuf,
+        /// The `io::Error` returned by the OS.
+        io_error: io::Error,
+    },
+    /// Failed to encode an image as PNG.
+    PngEncodeError {
+        /// Path to the output file.
+        path: PathBuf,
+        /// The error returned by the encoder.
+        png_error: png::EncodingError,
+    },
+    /// Failed to encode an animation as GIF.
+    GifEncodeError {
+        /// Path to the output file.
+        path: PathBuf,
+        /// The error returned by the encoder.
+        gif_error: gif::EncodingError,
+    },
+    /// A dimension or delay does not fit in the output format.
+    ValueTooLarge {
+        /// Path to the output file.
+        path: PathBuf,
+        /// Name of the value, such as `"width"`.
+        field: &'static str,
+        /// The value that does not fit.
+        value: u64,
+    },
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FileCreateError { path, io_error } => write!(
+                f,
+                "Failed to create file: `{}`. Error: {}",
+                path.display(),
+                io_error
+            ),
+            Self::PngEncodeError { path, png_error } => write!(
+                f,
+                "Failed to write PNG: `{}`. Error: {}",
+                path.display(),
+                png_error
+            ),
+            Self::GifEncodeError { path, gif_error } => write!(
+                f,
+                "Failed to write GIF: `{}`. Error: {}",
+                path.display(),
+                gif_error
+            ),
+            Self::ValueTooLarge { path, field, value } => write!(
+                f,
+                "Failed to write animation: `{}`. The {} `{}` is larger than the format's limit of `{}`.",
+                path.display(),
+                field,
+                value,
+                u16::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FileCreateError { io_error, .. } => Some(io_error),
+            Self::PngEncodeError { png_error, .. } => Some(png_error),
+            Self::GifEncodeError { gif_error, .. } => Some(gif_error),
+            Self::ValueTooLarge { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Runtime simulation of objects using their parsed data.
+
+pub(crate) use self::checksum::Checksum;
+
+pub use self::{
+    facing::Facing,
+    grab::Grab,
+    hit_box::HitBox,
+    input::Input,
+    instance_id::InstanceId,
+    object_instance::ObjectInstance,
+    position::Position,
+    replay::{
+        Replay, ReplayError, ReplayInput, ReplayParseError, ReplayRecorder, ReplaySpawn, ReplayTick,
+    },
+    sim_error::SimError,
+    simulation::Simulation,
+    velocity::Velocity,
+    weapon_hold::WeaponHold,
+};
+
+mod checksum;
+mod facing;
+mod grab;
+mod hit_box;
+mod input;
+mod instance_id;
+mod object_instance;
+mod position;
+mod replay;
+mod sim_error;
+mod simulation;
+mod velocity;
+mod weapon_hold;
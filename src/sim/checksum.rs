@@ -0,0 +1,38 @@
+/// FNV-1a hash of simulation state.
+///
+/// This is used instead of `std::hash::Hasher` implementations so that
+/// checksums are stable across Rust versions and platforms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Checksum(u64);
+
+impl Checksum {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Returns a new `Checksum`.
+    pub(crate) fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    /// Adds bytes to the checksum.
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(Self::PRIME)
+        });
+    }
+
+    /// Adds an integer to the checksum.
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Adds the bits of a float to the checksum.
+    pub(crate) fn write_f32(&mut self, value: f32) {
+        self.write(&value.to_bits().to_le_bytes());
+    }
+
+    /// Returns the checksum value.
+    pub(crate) fn finish(self) -> u64 {
+        self.0
+    }
+}
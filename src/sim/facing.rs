@@ -0,0 +1,30 @@
+/// Direction that an object is facing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Facing {
+    /// Facing to the right, the direction sprites are drawn in.
+    #[default]
+    Right,
+    /// Facing to the left, sprites are mirrored.
+    Left,
+}
+
+impl Facing {
+    /// Returns the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Right => Self::Left,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Returns `1.0` when facing right, and `-1.0` when facing left.
+    ///
+    /// Forward relative coordinates are multiplied by this to get the world
+    /// coordinates.
+    pub fn sign(self) -> f32 {
+        match self {
+            Self::Right => 1.,
+            Self::Left => -1.,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::sim::InstanceId;
+
+/// A character that is held by another object through `cpoint`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Grab {
+    /// Instance that is holding the character.
+    pub catcher: InstanceId,
+    /// Instance that is held.
+    pub caught: InstanceId,
+    /// Time left before the caught character breaks free.
+    ///
+    /// This is lowered by negative `decrease` values in the catcher's
+    /// `cpoint`.
+    pub timer: i32,
+    /// Whether other objects may hurt the caught character.
+    ///
+    /// Follows the `hurtable` value of the catcher's current `cpoint`.
+    pub hurtable: bool,
+    /// Whether the caught character is drawn in front of the catcher.
+    ///
+    /// Follows the `cover` value of the catcher's current `cpoint`.
+    pub cover: bool,
+}
@@ -0,0 +1,107 @@
+use crate::{
+    sim::{Facing, Position},
+    Bdy, Frame, Itr,
+};
+
+/// World space volume of an `itr` or `bdy`.
+///
+/// Bounds are inclusive of the minimum and exclusive of the maximum.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HitBox {
+    /// Left edge.
+    pub x_min: f32,
+    /// Right edge.
+    pub x_max: f32,
+    /// Top edge.
+    pub y_min: f32,
+    /// Bottom edge.
+    pub y_max: f32,
+    /// Back edge.
+    pub z_min: f32,
+    /// Front edge.
+    pub z_max: f32,
+}
+
+impl HitBox {
+    /// Returns the world space volume of a frame element.
+    ///
+    /// # Parameters
+    ///
+    /// * `position`: World position of the frame's center.
+    /// * `facing`: Direction the object is facing, mirroring the element when
+    ///   facing left.
+    /// * `frame`: Frame that the element belongs to.
+    /// * `x`, `y`, `w`, `h`, `z_width`: Element dimensions, relative to the
+    ///   top left of the frame's picture.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        position: Position,
+        facing: Facing,
+        frame: &Frame,
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+        z_width: u32,
+    ) -> Self {
+        let x_offset = (i64::from(x) - frame.center_x) as f32;
+        let (x_min, x_max) = match facing {
+            Facing::Right => {
+                let x_min = position.x + x_offset;
+                (x_min, x_min + w as f32)
+            }
+            Facing::Left => {
+                let x_max = position.x - x_offset;
+                (x_max - w as f32, x_max)
+            }
+        };
+        let y_min = position.y + (i64::from(y) - frame.center_y) as f32;
+
+        Self {
+            x_min,
+            x_max,
+            y_min,
+            y_max: y_min + h as f32,
+            z_min: position.z - z_width as f32,
+            z_max: position.z + z_width as f32 + 1.,
+        }
+    }
+
+    /// Returns the world space volume of an `itr`.
+    pub fn from_itr(position: Position, facing: Facing, frame: &Frame, itr: &Itr) -> Self {
+        Self::new(
+            position,
+            facing,
+            frame,
+            itr.x,
+            itr.y,
+            itr.w,
+            itr.h,
+            itr.z_width,
+        )
+    }
+
+    /// Returns the world space volume of a `bdy`.
+    pub fn from_bdy(position: Position, facing: Facing, frame: &Frame, bdy: &Bdy) -> Self {
+        Self::new(
+            position,
+            facing,
+            frame,
+            bdy.x,
+            bdy.y,
+            bdy.w,
+            bdy.h,
+            bdy.z_width,
+        )
+    }
+
+    /// Returns whether this volume overlaps another.
+    pub fn overlaps(&self, other: &HitBox) -> bool {
+        self.x_min < other.x_max
+            && other.x_min < self.x_max
+            && self.y_min < other.y_max
+            && other.y_min < self.y_max
+            && self.z_min < other.z_max
+            && other.z_min < self.z_max
+    }
+}
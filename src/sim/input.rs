@@ -0,0 +1,15 @@
+use crate::sim::InstanceId;
+
+/// Action requested of an instance, applied before a tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Input {
+    /// Attacks the held character, see `Simulation::grab_attack`.
+    GrabAttack,
+    /// Throws the held character, see `Simulation::grab_throw`.
+    GrabThrow,
+    /// Holds a weapon, see `Simulation::weapon_hold`.
+    WeaponHold {
+        /// Instance of the weapon to hold.
+        weapon: InstanceId,
+    },
+}
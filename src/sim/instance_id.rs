@@ -0,0 +1,24 @@
+use std::{
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+/// Identifies an object instance within a `Simulation`.
+///
+/// IDs are allocated in increasing order and never reused.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstanceId(pub usize);
+
+impl Deref for InstanceId {
+    type Target = usize;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for InstanceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
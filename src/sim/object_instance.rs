@@ -0,0 +1,36 @@
+use crate::{
+    sim::{Facing, InstanceId, Position, Velocity},
+    FrameNumber, ObjectId, ObjectType,
+};
+
+/// Runtime state of an object in a `Simulation`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectInstance {
+    /// ID of this instance within the simulation.
+    pub id: InstanceId,
+    /// ID of the object data this is an instance of.
+    pub object_id: ObjectId,
+    /// Type of the object.
+    pub object_type: ObjectType,
+    /// Frame that the instance is currently on.
+    pub frame_number: FrameNumber,
+    /// Number of ticks before the instance switches to the `next` frame.
+    pub ticks_remaining: u32,
+    /// Position of the frame's center in the world.
+    pub position: Position,
+    /// Current velocity.
+    pub velocity: Velocity,
+    /// Direction the instance is facing.
+    pub facing: Facing,
+    /// Instance that spawned this one through an `opoint`.
+    pub parent: Option<InstanceId>,
+    /// Health points.
+    pub hp: i32,
+    /// Health points lost when landing after being thrown.
+    pub(crate) throw_injury: i32,
+    /// Whether the frame was entered this tick, and its `opoint`s are yet to
+    /// be processed.
+    pub(crate) frame_entered: bool,
+    /// Whether the instance should be removed at the end of the tick.
+    pub(crate) deleted: bool,
+}
@@ -0,0 +1,19 @@
+/// Position of an object in the world.
+///
+/// `y` is `0` on the ground, and negative values are in the air.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+    /// Horizontal coordinate, increasing to the right.
+    pub x: f32,
+    /// Vertical coordinate, increasing downwards.
+    pub y: f32,
+    /// Depth coordinate, increasing towards the screen.
+    pub z: f32,
+}
+
+impl Position {
+    /// Returns a new `Position`.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+}
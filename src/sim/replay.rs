@@ -0,0 +1,447 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{
+    sim::{Facing, Input, InstanceId, Position, Simulation},
+    GameData,
+};
+
+pub use self::{
+    replay_error::ReplayError, replay_input::ReplayInput, replay_parse_error::ReplayParseError,
+    replay_recorder::ReplayRecorder, replay_spawn::ReplaySpawn, replay_tick::ReplayTick,
+};
+
+mod replay_error;
+mod replay_input;
+mod replay_parse_error;
+mod replay_recorder;
+mod replay_spawn;
+mod replay_tick;
+
+/// Recording of a simulation that can be played back deterministically.
+///
+/// A replay holds the instances that exist at the start, the inputs given in
+/// each tick, and the `Simulation::checksum` after each tick. Playing back a
+/// replay against changed game data reports the first tick whose state
+/// differs.
+///
+/// # Text Format
+///
+/// ```text
+/// lf2_replay 1
+/// # Lines beginning with `#` are comments.
+/// spawn <object_id> <frame_number> <x> <y> <z> <right|left>
+/// tick <checksum> [<instance_id>:<input>]*
+/// ```
+///
+/// Inputs are written as `grab_attack`, `grab_throw`, or
+/// `weapon_hold:<weapon_instance_id>`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Replay {
+    /// Instances that exist when the replay begins, in spawn order.
+    pub spawns: Vec<ReplaySpawn>,
+    /// Inputs and checksums for each tick.
+    pub ticks: Vec<ReplayTick>,
+}
+
+impl Replay {
+    /// First line of the replay text format.
+    pub const HEADER: &'static str = "lf2_replay 1";
+
+    /// Plays back the replay, returning the simulation after the last tick.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_data`: Objects that the replay's instances are created from.
+    pub fn play<'d>(&self, game_data: &'d GameData) -> Result<Simulation<'d>, ReplayError> {
+        let mut simulation = Simulation::new(game_data);
+        self.spawns.iter().try_for_each(|spawn| {
+            simulation
+                .spawn(
+                    spawn.object_id,
+                    spawn.frame_number,
+                    spawn.position,
+                    spawn.facing,
+                )
+                .map(|_| ())
+        })?;
+
+        self.ticks.iter().try_for_each(|tick| {
+            tick.inputs.iter().try_for_each(|replay_input| {
+                simulation
+                    .input(replay_input.instance_id, replay_input.input)
+                    .map(|_| ())
+            })?;
+            simulation.tick()?;
+
+            let checksum_actual = simulation.checksum();
+            if checksum_actual == tick.checksum {
+                Ok(())
+            } else {
+                Err(ReplayError::Desync {
+                    tick: simulation.tick_count(),
+                    checksum_expected: tick.checksum,
+                    checksum_actual,
+                })
+            }
+        })?;
+
+        Ok(simulation)
+    }
+
+    fn parse_spawn(tokens: &[&str]) -> Option<ReplaySpawn> {
+        match tokens {
+            [object_id, frame_number, x, y, z, facing] => {
+                let facing = match *facing {
+                    "right" => Facing::Right,
+                    "left" => Facing::Left,
+                    _ => return None,
+                };
+                Some(ReplaySpawn {
+                    object_id: object_id.parse().ok()?,
+                    frame_number: frame_number.parse().ok()?,
+                    position: Position::new(x.parse().ok()?, y.parse().ok()?, z.parse().ok()?),
+                    facing,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_tick(tokens: &[&str]) -> Option<ReplayTick> {
+        let (checksum, inputs) = tokens.split_first()?;
+        let checksum = u64::from_str_radix(checksum, 16).ok()?;
+        let inputs = inputs
+            .iter()
+            .map(|input| Self::parse_input(input))
+            .collect::<Option<Vec<ReplayInput>>>()?;
+
+        Some(ReplayTick { inputs, checksum })
+    }
+
+    fn parse_input(token: &str) -> Option<ReplayInput> {
+        let mut parts = token.split(':');
+        let instance_id = InstanceId(parts.next()?.parse().ok()?);
+        let input = match (parts.next()?, parts.next()) {
+            ("grab_attack", None) => Input::GrabAttack,
+            ("grab_throw", None) => Input::GrabThrow,
+            ("weapon_hold", Some(weapon)) => Input::WeaponHold {
+                weapon: InstanceId(weapon.parse().ok()?),
+            },
+            _ => return None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(ReplayInput { instance_id, input })
+    }
+}
+
+impl Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+
+        self.spawns.iter().try_for_each(|spawn| {
+            let facing = match spawn.facing {
+                Facing::Right => "right",
+                Facing::Left => "left",
+            };
+            writeln!(
+                f,
+                "spawn {} {} {} {} {} {}",
+                spawn.object_id,
+                spawn.frame_number,
+                spawn.position.x,
+                spawn.position.y,
+                spawn.position.z,
+                facing
+            )
+        })?;
+
+        self.ticks.iter().try_for_each(|tick| {
+            write!(f, "tick {:016x}", tick.checksum)?;
+            tick.inputs.iter().try_for_each(|replay_input| {
+                write!(f, " {}:", replay_input.instance_id)?;
+                match replay_input.input {
+                    Input::GrabAttack => write!(f, "grab_attack"),
+                    Input::GrabThrow => write!(f, "grab_throw"),
+                    Input::WeaponHold { weapon } => write!(f, "weapon_hold:{}", weapon),
+                }
+            })?;
+            writeln!(f)
+        })
+    }
+}
+
+impl FromStr for Replay {
+    type Err = ReplayParseError;
+
+    fn from_str(s: &str) -> Result<Replay, ReplayParseError> {
+        let mut lines = s.lines().enumerate();
+        match lines.next() {
+            Some((_, line)) if line.trim() == Self::HEADER => {}
+            line => {
+                return Err(ReplayParseError::HeaderInvalid {
+                    line: line.map(|(_, line)| line.to_string()).unwrap_or_default(),
+                })
+            }
+        }
+
+        lines
+            .filter(|(_, line)| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with('#')
+            })
+            .try_fold(Replay::default(), |mut replay, (index, line)| {
+                let tokens = line.split_whitespace().collect::<Vec<&str>>();
+                let parsed = match tokens.split_first() {
+                    Some((&"spawn", tokens)) => Self::parse_spawn(tokens)
+                        .map(|spawn| replay.spawns.push(spawn))
+                        .is_some(),
+                    Some((&"tick", tokens)) => Self::parse_tick(tokens)
+                        .map(|tick| replay.ticks.push(tick))
+                        .is_some(),
+                    _ => false,
+                };
+
+                if parsed {
+                    Ok(replay)
+                } else {
+                    Err(ReplayParseError::LineInvalid {
+                        line_number: index + 1,
+                        line: line.to_string(),
+                    })
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, num::NonZeroU32};
+
+    use super::{Replay, ReplayError, ReplayInput, ReplayRecorder};
+    use crate::{
+        sim::{Facing, Input, InstanceId, Position, Simulation},
+        FrameNumber, GameData, GameObject, ObjectData, ObjectId, ObjectType, Wait,
+    };
+
+    const FROZEN: ObjectId = ObjectId(11);
+    const FIGHTER: ObjectId = ObjectId(1);
+    const WEAPON: ObjectId = ObjectId(2);
+
+    fn game_data(object_data: ObjectData) -> GameData {
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: FROZEN,
+            object_type: ObjectType::Character,
+            object_data,
+        });
+        game_data
+    }
+
+    fn replay_record(game_data: &GameData) -> Replay {
+        let mut replay_recorder = ReplayRecorder::new(game_data);
+        [
+            (Position::new(100., 0., 300.), Facing::Right),
+            (Position::new(400., 0., 300.), Facing::Left),
+        ]
+        .iter()
+        .for_each(|(position, facing)| {
+            replay_recorder
+                .spawn(FROZEN, FrameNumber(0), *position, *facing)
+                .unwrap();
+        });
+        (0..60).for_each(|_| {
+            replay_recorder.tick(&[]).unwrap();
+        });
+        replay_recorder.into_replay()
+    }
+
+    #[test]
+    fn recorded_replay_round_trips_through_text_and_plays_back() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+        let game_data = game_data(object_data);
+        let replay = replay_record(&game_data);
+
+        let replay_parsed = replay.to_string().parse::<Replay>().unwrap();
+        assert_eq!(replay, replay_parsed);
+
+        let simulation = replay_parsed.play(&game_data).unwrap();
+        assert_eq!(60, simulation.tick_count());
+    }
+
+    #[test]
+    fn replay_desyncs_when_frame_data_changes() {
+        let object_data = ObjectData::try_from(include_str!("../../examples/frozen.txt")).unwrap();
+        let replay = replay_record(&game_data(object_data.clone()));
+
+        let mut object_data_edited = object_data;
+        if let Some(mut frame) = object_data_edited.frames.get_mut(FrameNumber(0)) {
+            frame.wait = Wait(NonZeroU32::new(2).unwrap());
+        }
+        let game_data_edited = game_data(object_data_edited);
+        let result = replay.play(&game_data_edited);
+
+        assert!(matches!(result, Err(ReplayError::Desync { .. })));
+    }
+
+    /// Returns game data with a character that catches on frame 1, and a light
+    /// weapon that it holds while standing.
+    fn game_data_fighter() -> GameData {
+        let frame = |number: u32, name: &str, wait: u32, next: i32, elements: &str| {
+            format!(
+                "<frame> {} {}\n\
+                 \x20 pic: 0  wait: {}  next: {}  centerx: 40  centery: 80\n\
+                 {}\
+                 <frame_end>\n",
+                number, name, wait, next, elements
+            )
+        };
+        let bdy = "  bdy:\n     kind: 0  x: 0  y: 0  w: 80  h: 80\n  bdy_end:\n";
+        let hold = "  wpoint:\n     kind: 1  x: 40  y: 50  weaponact: 20\n  wpoint_end:\n";
+        let catch = "  itr:\n     kind: 3  x: 40  y: 0  w: 80  h: 80  \
+                     catchingact: 120 120  caughtact: 130 130\n  itr_end:\n";
+        let c_point = |tags: &str| {
+            format!(
+                "  cpoint:\n     kind: 1  x: 50  y: 40  vaction: 130  {}\n  cpoint_end:\n",
+                tags
+            )
+        };
+
+        let fighter = [
+            "<bmp_begin>\n<bmp_end>\n".to_string(),
+            frame(0, "standing", 1, 0, &format!("{}{}", hold, bdy)),
+            frame(1, "catch", 1, 0, &format!("{}{}", catch, bdy)),
+            frame(
+                120,
+                "catching",
+                5,
+                120,
+                &c_point("aaction: 121  taction: 122"),
+            ),
+            frame(121, "catching_attack", 2, 120, &c_point("injury: 10")),
+            frame(
+                122,
+                "throwing",
+                2,
+                999,
+                &c_point("throwvx: 10  throwvy: -5"),
+            ),
+            frame(
+                130,
+                "caught",
+                10,
+                0,
+                "  cpoint:\n     kind: 2  x: 30  y: 50\n  cpoint_end:\n",
+            ),
+            frame(181, "falling", 10, 0, ""),
+            frame(211, "jump", 10, 0, ""),
+            frame(212, "in_the_sky", 10, 0, ""),
+        ]
+        .concat();
+        let weapon = "<bmp_begin>\n<bmp_end>\n\
+             <frame> 0 in_the_sky\n  pic: 0  wait: 10  next: 0\n<frame_end>\n\
+             <frame> 20 held\n  pic: 0  wait: 10  next: 0\n<frame_end>\n\
+             <frame> 40 throwing\n  pic: 0  wait: 10  next: 0\n<frame_end>\n";
+
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: FIGHTER,
+            object_type: ObjectType::Character,
+            object_data: ObjectData::try_from(fighter.as_str()).unwrap(),
+        });
+        game_data.insert(GameObject {
+            id: WEAPON,
+            object_type: ObjectType::LightWeapon,
+            object_data: ObjectData::try_from(weapon).unwrap(),
+        });
+        game_data
+    }
+
+    #[test]
+    fn replay_with_grab_and_weapon_inputs_round_trips_and_plays_back() {
+        let game_data = game_data_fighter();
+        let mut replay_recorder = ReplayRecorder::new(&game_data);
+        let catcher = replay_recorder
+            .spawn(
+                FIGHTER,
+                FrameNumber(1),
+                Position::new(100., 0., 200.),
+                Facing::Right,
+            )
+            .unwrap();
+        let caught = replay_recorder
+            .spawn(
+                FIGHTER,
+                FrameNumber(0),
+                Position::new(150., 0., 200.),
+                Facing::Left,
+            )
+            .unwrap();
+        let weapon = replay_recorder
+            .spawn(
+                WEAPON,
+                FrameNumber(0),
+                Position::new(300., 0., 200.),
+                Facing::Left,
+            )
+            .unwrap();
+
+        let input_at = |tick: u32| match tick {
+            3 => Some(Input::GrabAttack),
+            6 => Some(Input::GrabThrow),
+            12 => Some(Input::WeaponHold { weapon }),
+            _ => None,
+        };
+        (1..=30).for_each(|tick| {
+            let inputs = input_at(tick)
+                .map(|input| {
+                    vec![ReplayInput {
+                        instance_id: catcher,
+                        input,
+                    }]
+                })
+                .unwrap_or_default();
+            replay_recorder.tick(&inputs).unwrap();
+        });
+
+        let simulation = replay_recorder.simulation();
+        let caught_hp = simulation.instance(caught).unwrap().hp;
+        assert!(simulation.grabs().is_empty());
+        assert_eq!(Simulation::HP_MAX - 10, caught_hp);
+        assert_eq!(1, simulation.weapon_holds().len());
+        let checksum = simulation.checksum();
+
+        let replay = replay_recorder.into_replay();
+        let replay_str = replay.to_string();
+        assert!(replay_str.contains(" 0:grab_attack"));
+        assert!(replay_str.contains(" 0:grab_throw"));
+        assert!(replay_str.contains(" 0:weapon_hold:2"));
+
+        let replay_parsed = replay_str.parse::<Replay>().unwrap();
+        assert_eq!(replay, replay_parsed);
+        let simulation = replay_parsed.play(&game_data).unwrap();
+        assert_eq!(checksum, simulation.checksum());
+        assert_eq!(caught_hp, simulation.instance(InstanceId(1)).unwrap().hp);
+    }
+
+    #[test]
+    fn recorder_rejects_spawns_after_the_first_tick() {
+        let game_data = game_data_fighter();
+        let mut replay_recorder = ReplayRecorder::new(&game_data);
+        replay_recorder.tick(&[]).unwrap();
+
+        let result = replay_recorder.spawn(
+            FIGHTER,
+            FrameNumber(0),
+            Position::new(100., 0., 200.),
+            Facing::Right,
+        );
+
+        assert_eq!(Err(ReplayError::SpawnAfterStart { tick: 1 }), result);
+    }
+}
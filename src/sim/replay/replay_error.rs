@@ -0,0 +1,54 @@
+use std::{fmt, fmt::Display};
+
+use crate::sim::SimError;
+
+/// Errors when recording or playing back a `Replay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The simulation failed to run.
+    Sim(SimError),
+    /// The simulation state differs from when the replay was recorded.
+    Desync {
+        /// Tick whose checksum differs, starting from `1`.
+        tick: u64,
+        /// Checksum that was recorded.
+        checksum_expected: u64,
+        /// Checksum from playing back the replay.
+        checksum_actual: u64,
+    },
+    /// An instance was spawned after the first tick was recorded.
+    SpawnAfterStart {
+        /// Number of ticks that had been recorded.
+        tick: u64,
+    },
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Sim(sim_error) => write!(f, "{}", sim_error),
+            Self::Desync {
+                tick,
+                checksum_expected,
+                checksum_actual,
+            } => write!(
+                f,
+                "Replay desynced at tick `{}`: expected checksum `{:016x}`, got `{:016x}`.",
+                tick, checksum_expected, checksum_actual
+            ),
+            Self::SpawnAfterStart { tick } => write!(
+                f,
+                "Instances may only be spawned before the first tick, but `{}` ticks were recorded.",
+                tick
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<SimError> for ReplayError {
+    fn from(sim_error: SimError) -> Self {
+        Self::Sim(sim_error)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::sim::{Input, InstanceId};
+
+/// Input given to an instance in one replay tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayInput {
+    /// Instance that receives the input.
+    pub instance_id: InstanceId,
+    /// The input.
+    pub input: Input,
+}
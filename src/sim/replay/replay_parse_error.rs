@@ -0,0 +1,40 @@
+use std::{fmt, fmt::Display};
+
+/// Errors when parsing a string as a `Replay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayParseError {
+    /// The first line is not a supported replay header.
+    HeaderInvalid {
+        /// The first line.
+        line: String,
+    },
+    /// A line could not be parsed.
+    LineInvalid {
+        /// Line number, starting from `1`.
+        line_number: usize,
+        /// Contents of the line.
+        line: String,
+    },
+}
+
+impl Display for ReplayParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::HeaderInvalid { line } => write!(
+                f,
+                "Expected replay header `{}`, but found `{}`.",
+                crate::sim::Replay::HEADER,
+                line
+            ),
+            Self::LineInvalid { line_number, line } => {
+                write!(
+                    f,
+                    "Failed to parse replay line {}: `{}`.",
+                    line_number, line
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayParseError {}
@@ -0,0 +1,86 @@
+use crate::{
+    sim::{
+        Facing, InstanceId, Position, Replay, ReplayError, ReplayInput, ReplaySpawn, ReplayTick,
+        SimError, Simulation,
+    },
+    FrameNumber, GameData, ObjectId,
+};
+
+/// Runs a `Simulation` while recording a `Replay` of it.
+///
+/// Instances can only be spawned before the first tick, as a replay only
+/// records the instances that exist when it begins.
+#[derive(Clone, Debug)]
+pub struct ReplayRecorder<'d> {
+    /// The simulation being recorded.
+    simulation: Simulation<'d>,
+    /// The replay recorded so far.
+    replay: Replay,
+}
+
+impl<'d> ReplayRecorder<'d> {
+    /// Returns a new `ReplayRecorder` with no instances.
+    pub fn new(game_data: &'d GameData) -> Self {
+        Self {
+            simulation: Simulation::new(game_data),
+            replay: Replay::default(),
+        }
+    }
+
+    /// Returns the simulation being recorded.
+    pub fn simulation(&self) -> &Simulation<'d> {
+        &self.simulation
+    }
+
+    /// Spawns an instance at the start of the replay.
+    ///
+    /// Returns `ReplayError::SpawnAfterStart` if a tick has already been
+    /// recorded. See `Simulation::spawn`.
+    pub fn spawn(
+        &mut self,
+        object_id: ObjectId,
+        frame_number: FrameNumber,
+        position: Position,
+        facing: Facing,
+    ) -> Result<InstanceId, ReplayError> {
+        let tick = self.simulation.tick_count();
+        if tick != 0 {
+            return Err(ReplayError::SpawnAfterStart { tick });
+        }
+
+        let instance_id = self
+            .simulation
+            .spawn(object_id, frame_number, position, facing)?;
+        self.replay.spawns.push(ReplaySpawn {
+            object_id,
+            frame_number,
+            position,
+            facing,
+        });
+
+        Ok(instance_id)
+    }
+
+    /// Applies inputs then runs one tick, returning the tick's checksum.
+    pub fn tick(&mut self, inputs: &[ReplayInput]) -> Result<u64, SimError> {
+        inputs.iter().try_for_each(|replay_input| {
+            self.simulation
+                .input(replay_input.instance_id, replay_input.input)
+                .map(|_| ())
+        })?;
+        self.simulation.tick()?;
+
+        let checksum = self.simulation.checksum();
+        self.replay.ticks.push(ReplayTick {
+            inputs: inputs.to_vec(),
+            checksum,
+        });
+
+        Ok(checksum)
+    }
+
+    /// Returns the recorded replay.
+    pub fn into_replay(self) -> Replay {
+        self.replay
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    sim::{Facing, Position},
+    FrameNumber, ObjectId,
+};
+
+/// Instance that exists when a replay begins.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReplaySpawn {
+    /// ID of the object to spawn.
+    pub object_id: ObjectId,
+    /// Frame that the instance begins on.
+    pub frame_number: FrameNumber,
+    /// Where the frame's center is placed.
+    pub position: Position,
+    /// Direction the instance faces.
+    pub facing: Facing,
+}
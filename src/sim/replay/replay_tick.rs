@@ -0,0 +1,10 @@
+use crate::sim::ReplayInput;
+
+/// Inputs and resulting state of one replay tick.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplayTick {
+    /// Inputs applied before the tick is run.
+    pub inputs: Vec<ReplayInput>,
+    /// `Simulation::checksum` after the tick is run.
+    pub checksum: u64,
+}
@@ -0,0 +1,52 @@
+use std::{fmt, fmt::Display};
+
+use crate::{sim::InstanceId, FrameNumber, ObjectId};
+
+/// Errors when running a `Simulation`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimError {
+    /// An object ID is not present in the `GameData`.
+    ObjectNotFound {
+        /// ID of the object that was requested.
+        object_id: ObjectId,
+    },
+    /// An object does not have a frame with the requested number.
+    FrameNotFound {
+        /// ID of the object whose frame was requested.
+        object_id: ObjectId,
+        /// Frame number that does not exist.
+        frame_number: FrameNumber,
+    },
+    /// An instance is not live in the simulation.
+    InstanceNotFound {
+        /// ID of the instance that was requested.
+        instance_id: InstanceId,
+    },
+}
+
+impl Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ObjectNotFound { object_id } => {
+                write!(f, "Object `{}` is not present in the game data.", object_id)
+            }
+            Self::FrameNotFound {
+                object_id,
+                frame_number,
+            } => write!(
+                f,
+                "Object `{}` does not have frame `{}`.",
+                object_id, frame_number
+            ),
+            Self::InstanceNotFound { instance_id } => {
+                write!(
+                    f,
+                    "Instance `{}` is not live in the simulation.",
+                    instance_id
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
@@ -0,0 +1,1410 @@
+use crate::{
+    sim::{
+        Checksum, Facing, Grab, HitBox, Input, InstanceId, ObjectInstance, Position, SimError,
+        Velocity, WeaponHold,
+    },
+    CPoint, CPointKind, Element, Frame, FrameNumber, FrameNumberNext, GameData, GameObject, Itr,
+    ItrKind, OPoint, OPointFacingDir, ObjectId, ObjectType, State, WPoint, WPointKind,
+};
+
+/// Runs objects through their frames, one tick (TU) at a time.
+///
+/// Each tick:
+///
+/// 1. Every instance moves by its velocity, and gravity / friction is applied.
+/// 2. Every instance counts down its frame `wait`, and switches to `next` when
+///    the wait is over.
+/// 3. Catching `itr`s that overlap a character's `bdy` start a `Grab`.
+/// 4. Held characters follow the catcher's `cpoint`, and are released or thrown
+///    when the catcher's frame says so.
+/// 5. Held weapons follow the holder's `wpoint`, and are dropped or thrown when
+///    the holder's frame says so.
+/// 6. Instances that entered a frame spawn the objects in that frame's
+///    `opoint`s.
+/// 7. Instances that were deleted are removed.
+#[derive(Clone, Debug)]
+pub struct Simulation<'d> {
+    /// Objects that may be instantiated.
+    game_data: &'d GameData,
+    /// Live instances, in the order they were spawned.
+    instances: Vec<ObjectInstance>,
+    /// Characters that are being held.
+    grabs: Vec<Grab>,
+    /// Weapons that are being held.
+    weapon_holds: Vec<WeaponHold>,
+    /// ID to assign to the next spawned instance.
+    instance_id_next: usize,
+    /// Number of ticks that have been run.
+    tick_count: u64,
+}
+
+impl<'d> Simulation<'d> {
+    /// Acceleration due to gravity, in pixels per tick per tick.
+    pub const GRAVITY: f32 = 1.7;
+    /// Speed that objects on the ground lose each tick.
+    pub const FRICTION: f32 = 1.;
+    /// `dvx`, `dvy`, or `dvz` value that stops movement along that axis.
+    pub const D_V_STOP: i64 = 550;
+    /// Difference in Z velocity between copies spawned by the same `opoint`.
+    ///
+    /// Copies are fanned out symmetrically around the spawn point.
+    pub const O_POINT_SPREAD_VZ: f32 = 2.;
+    /// Frame that characters switch to for `next: 999` while in mid air.
+    pub const FRAME_IN_SKY: FrameNumber = FrameNumber(212);
+    /// Frame that characters switch to for `next: 999` while on the ground.
+    pub const FRAME_STANDING: FrameNumber = FrameNumber(0);
+    /// Frame that held characters switch to when released without falling.
+    pub const FRAME_JUMP: FrameNumber = FrameNumber(211);
+    /// Frame that held characters switch to when they are dropped.
+    pub const FRAME_FALLING: FrameNumber = FrameNumber(181);
+    /// Health points that instances are spawned with.
+    pub const HP_MAX: i32 = 500;
+    /// Time a character may be held for, before negative `decrease` values.
+    pub const GRAB_TIMER_INITIAL: i32 = 300;
+    /// `decrease` magnitude from which a released character is dropped into
+    /// the falling frames instead of the jump frames.
+    pub const GRAB_DECREASE_FALL: i32 = 7;
+    /// Frame that weapons switch to when they are dropped, and heavy weapons
+    /// switch to when thrown.
+    pub const FRAME_WEAPON_IN_SKY: FrameNumber = FrameNumber(0);
+    /// First frame of the light weapon throwing frames.
+    pub const FRAME_WEAPON_THROWING: FrameNumber = FrameNumber(40);
+
+    /// Returns a new `Simulation` with no instances.
+    pub fn new(game_data: &'d GameData) -> Self {
+        Self {
+            game_data,
+            instances: Vec::new(),
+            grabs: Vec::new(),
+            weapon_holds: Vec::new(),
+            instance_id_next: 0,
+            tick_count: 0,
+        }
+    }
+
+    /// Returns the game data that instances are created from.
+    pub fn game_data(&self) -> &'d GameData {
+        self.game_data
+    }
+
+    /// Returns the number of ticks that have been run.
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Returns the live instances, in the order they were spawned.
+    pub fn instances(&self) -> &[ObjectInstance] {
+        &self.instances
+    }
+
+    /// Returns the instance with the given ID, if it is still live.
+    pub fn instance(&self, instance_id: InstanceId) -> Option<&ObjectInstance> {
+        self.instance_index(instance_id)
+            .map(|index| &self.instances[index])
+    }
+
+    /// Returns the characters that are being held.
+    pub fn grabs(&self) -> &[Grab] {
+        &self.grabs
+    }
+
+    /// Returns whether other objects may hurt an instance.
+    ///
+    /// Characters held through a `cpoint` without `hurtable` may not be hurt.
+    pub fn hurtable(&self, instance_id: InstanceId) -> bool {
+        self.grabs
+            .iter()
+            .find(|grab| grab.caught == instance_id)
+            .map(|grab| grab.hurtable)
+            .unwrap_or(true)
+    }
+
+    /// Returns the weapons that are being held.
+    pub fn weapon_holds(&self) -> &[WeaponHold] {
+        &self.weapon_holds
+    }
+
+    /// Returns the frame that an instance is currently on.
+    pub fn frame(&self, instance: &ObjectInstance) -> Option<&'d Frame> {
+        self.game_data
+            .get(instance.object_id)
+            .and_then(|game_object| Self::frame_find(game_object, instance.frame_number).ok())
+    }
+
+    /// Spawns an instance of an object at the given frame.
+    ///
+    /// # Parameters
+    ///
+    /// * `object_id`: ID of the object to spawn.
+    /// * `frame_number`: Frame that the instance begins on.
+    /// * `position`: Where the frame's center is placed.
+    /// * `facing`: Direction the instance faces.
+    pub fn spawn(
+        &mut self,
+        object_id: ObjectId,
+        frame_number: FrameNumber,
+        position: Position,
+        facing: Facing,
+    ) -> Result<InstanceId, SimError> {
+        let action = FrameNumberNext(*frame_number as isize);
+        self.instantiate(object_id, action, position, facing, None)
+            .map(|instance| instance.id)
+    }
+
+    /// Switches a catcher to its `cpoint`'s `aaction`, attacking the held
+    /// character.
+    ///
+    /// Returns whether the catcher is holding a character with an `aaction`.
+    pub fn grab_attack(&mut self, catcher: InstanceId) -> Result<bool, SimError> {
+        self.grab_action(catcher, |c_point| c_point.a_action)
+    }
+
+    /// Switches a catcher to its `cpoint`'s `taction`, throwing the held
+    /// character.
+    ///
+    /// Returns whether the catcher is holding a character with a `taction`.
+    pub fn grab_throw(&mut self, catcher: InstanceId) -> Result<bool, SimError> {
+        self.grab_action(catcher, |c_point| c_point.t_action)
+    }
+
+    /// Has a character hold a light or heavy weapon.
+    ///
+    /// Returns `false` if either instance is already holding or held, or the
+    /// objects are not a character and weapon.
+    pub fn weapon_hold(
+        &mut self,
+        holder: InstanceId,
+        weapon: InstanceId,
+    ) -> Result<bool, SimError> {
+        let holder_index = self
+            .instance_index(holder)
+            .ok_or(SimError::InstanceNotFound {
+                instance_id: holder,
+            })?;
+        let weapon_index = self
+            .instance_index(weapon)
+            .ok_or(SimError::InstanceNotFound {
+                instance_id: weapon,
+            })?;
+
+        let is_weapon = matches!(
+            self.instances[weapon_index].object_type,
+            ObjectType::LightWeapon | ObjectType::HeavyWeapon
+        );
+        let is_holding = self.weapon_holds.iter().any(|weapon_hold| {
+            [holder, weapon].contains(&weapon_hold.holder)
+                || [holder, weapon].contains(&weapon_hold.weapon)
+        });
+        if self.instances[holder_index].object_type != ObjectType::Character
+            || !is_weapon
+            || is_holding
+        {
+            return Ok(false);
+        }
+
+        self.weapon_holds.push(WeaponHold {
+            holder,
+            weapon,
+            attacking: Default::default(),
+        });
+        self.weapon_holds_update()?;
+
+        Ok(true)
+    }
+
+    /// Returns the `itr` values that are in effect for an instance.
+    ///
+    /// `ItrKind::WeaponStrength` `itr`s on a held weapon take their values from
+    /// the weapon strength entry selected by the holder's `wpoint`
+    /// `attacking`.
+    pub fn itr_effective(&self, instance: &ObjectInstance, itr: &Itr) -> Itr {
+        if itr.kind != ItrKind::WeaponStrength {
+            return *itr;
+        }
+
+        let weapon_strength = self
+            .weapon_holds
+            .iter()
+            .find(|weapon_hold| weapon_hold.weapon == instance.id)
+            .and_then(|weapon_hold| {
+                self.game_data
+                    .get(instance.object_id)?
+                    .object_data
+                    .weapon_strength_list
+                    .entry(weapon_hold.attacking)
+            });
+
+        match weapon_strength {
+            Some(weapon_strength) => Itr {
+                d_vx: weapon_strength.d_vx,
+                d_vy: weapon_strength.d_vy,
+                a_rest: weapon_strength.arest,
+                v_rest: weapon_strength.vrest,
+                fall: weapon_strength.fall,
+                b_defend: weapon_strength.b_defend,
+                injury: weapon_strength.injury,
+                effect: weapon_strength.effect,
+                ..*itr
+            },
+            None => *itr,
+        }
+    }
+
+    /// Applies an input to an instance.
+    ///
+    /// Returns whether the input had an effect.
+    pub fn input(&mut self, instance_id: InstanceId, input: Input) -> Result<bool, SimError> {
+        match input {
+            Input::GrabAttack => self.grab_attack(instance_id),
+            Input::GrabThrow => self.grab_throw(instance_id),
+            Input::WeaponHold { weapon } => self.weapon_hold(instance_id, weapon),
+        }
+    }
+
+    /// Returns a hash of the simulation state.
+    ///
+    /// Different checksums mean the simulations are in different states. The
+    /// same checksum only means the states are very likely the same, as
+    /// different states may hash to the same value. This is used to detect
+    /// when a replay no longer matches the game data.
+    pub fn checksum(&self) -> u64 {
+        let mut checksum = Checksum::new();
+        checksum.write_u64(self.tick_count);
+        checksum.write_u64(self.instance_id_next as u64);
+
+        self.instances.iter().for_each(|instance| {
+            checksum.write_u64(*instance.id as u64);
+            checksum.write_u64(*instance.object_id as u64);
+            checksum.write_u64(*instance.frame_number as u64);
+            checksum.write_u64(u64::from(instance.ticks_remaining));
+            checksum.write_f32(instance.position.x);
+            checksum.write_f32(instance.position.y);
+            checksum.write_f32(instance.position.z);
+            checksum.write_f32(instance.velocity.x);
+            checksum.write_f32(instance.velocity.y);
+            checksum.write_f32(instance.velocity.z);
+            checksum.write_u64(instance.facing as u64);
+            checksum.write_u64(
+                instance
+                    .parent
+                    .map(|parent| *parent as u64 + 1)
+                    .unwrap_or(0),
+            );
+            checksum.write_u64(instance.hp as u64);
+            checksum.write_u64(instance.throw_injury as u64);
+        });
+        self.grabs.iter().for_each(|grab| {
+            checksum.write_u64(*grab.catcher as u64);
+            checksum.write_u64(*grab.caught as u64);
+            checksum.write_u64(grab.timer as u64);
+            checksum.write_u64(grab.hurtable as u64);
+            checksum.write_u64(grab.cover as u64);
+        });
+        self.weapon_holds.iter().for_each(|weapon_hold| {
+            checksum.write_u64(*weapon_hold.holder as u64);
+            checksum.write_u64(*weapon_hold.weapon as u64);
+            checksum.write_u64(*weapon_hold.attacking as u64);
+        });
+
+        checksum.finish()
+    }
+
+    /// Runs the simulation for one tick.
+    pub fn tick(&mut self) -> Result<(), SimError> {
+        self.tick_count += 1;
+
+        (0..self.instances.len()).try_for_each(|index| self.instance_step(index))?;
+
+        self.grabs_begin()?;
+        self.grabs_update()?;
+        self.weapon_holds_update()?;
+
+        // Instances spawned here only process their own `opoint`s next tick, so
+        // that objects which spawn each other cannot loop within one tick.
+        let instance_count = self.instances.len();
+        (0..instance_count).try_for_each(|index| self.o_points_spawn(index))?;
+
+        self.instances.retain(|instance| !instance.deleted);
+        let instances = &self.instances;
+        self.grabs
+            .retain(|grab| Self::instances_live(instances, grab.catcher, grab.caught));
+        self.weapon_holds.retain(|weapon_hold| {
+            Self::instances_live(instances, weapon_hold.holder, weapon_hold.weapon)
+        });
+
+        Ok(())
+    }
+
+    fn instance_index(&self, instance_id: InstanceId) -> Option<usize> {
+        self.instances
+            .binary_search_by_key(&instance_id, |instance| instance.id)
+            .ok()
+    }
+
+    fn game_object(&self, object_id: ObjectId) -> Result<&'d GameObject, SimError> {
+        self.game_data
+            .get(object_id)
+            .ok_or(SimError::ObjectNotFound { object_id })
+    }
+
+    fn frame_find(
+        game_object: &'d GameObject,
+        frame_number: FrameNumber,
+    ) -> Result<&'d Frame, SimError> {
+        game_object
+            .object_data
+            .frames
+            .get(frame_number)
+            .ok_or(SimError::FrameNotFound {
+                object_id: game_object.id,
+                frame_number,
+            })
+    }
+
+    fn instantiate(
+        &mut self,
+        object_id: ObjectId,
+        action: FrameNumberNext,
+        position: Position,
+        facing: Facing,
+        parent: Option<InstanceId>,
+    ) -> Result<&mut ObjectInstance, SimError> {
+        let game_object = self.game_object(object_id)?;
+        let facing = if action.facing_switch() {
+            facing.reversed()
+        } else {
+            facing
+        };
+        let frame_number = action.abs();
+
+        let mut instance = ObjectInstance {
+            id: InstanceId(self.instance_id_next),
+            object_id,
+            object_type: game_object.object_type,
+            frame_number,
+            ticks_remaining: 0,
+            position,
+            velocity: Velocity::default(),
+            facing,
+            parent,
+            hp: Self::HP_MAX,
+            throw_injury: 0,
+            frame_entered: false,
+            deleted: false,
+        };
+        Self::frame_enter(game_object, &mut instance, frame_number)?;
+
+        self.instance_id_next += 1;
+        self.instances.push(instance);
+
+        Ok(self
+            .instances
+            .last_mut()
+            .expect("Expected instance to exist as it was just pushed."))
+    }
+
+    fn instance_step(&mut self, index: usize) -> Result<(), SimError> {
+        let game_object = self.game_object(self.instances[index].object_id)?;
+        let instance = &mut self.instances[index];
+        if instance.deleted {
+            return Ok(());
+        }
+
+        Self::physics_step(instance);
+
+        instance.ticks_remaining = instance.ticks_remaining.saturating_sub(1);
+        if instance.ticks_remaining == 0 {
+            let frame = Self::frame_find(game_object, instance.frame_number)?;
+            Self::frame_transition(game_object, instance, frame, frame.next_frame)?;
+        }
+
+        Ok(())
+    }
+
+    fn physics_step(instance: &mut ObjectInstance) {
+        let ObjectInstance {
+            position, velocity, ..
+        } = instance;
+        position.x += velocity.x;
+        position.y += velocity.y;
+        position.z += velocity.z;
+
+        if instance.object_type == ObjectType::Attack {
+            return;
+        }
+
+        if position.y >= 0. {
+            if position.y > 0. || velocity.y > 0. {
+                // Landed this tick.
+                instance.hp -= instance.throw_injury;
+                instance.throw_injury = 0;
+            }
+
+            position.y = 0.;
+            velocity.y = velocity.y.min(0.);
+            velocity.x = Self::friction_apply(velocity.x);
+            velocity.z = Self::friction_apply(velocity.z);
+        } else {
+            velocity.y += Self::GRAVITY;
+        }
+    }
+
+    fn friction_apply(speed: f32) -> f32 {
+        if speed.abs() <= Self::FRICTION {
+            0.
+        } else {
+            speed - Self::FRICTION * speed.signum()
+        }
+    }
+
+    /// Switches an instance to the frame indicated by a `next` value.
+    fn frame_transition(
+        game_object: &'d GameObject,
+        instance: &mut ObjectInstance,
+        frame_current: &Frame,
+        next: FrameNumberNext,
+    ) -> Result<(), SimError> {
+        match next {
+            FrameNumberNext::NONE => {
+                instance.ticks_remaining = frame_current.wait.ticks();
+                Ok(())
+            }
+            FrameNumberNext::STANDING => {
+                let frame_number =
+                    if instance.object_type == ObjectType::Character && instance.position.y < 0. {
+                        Self::FRAME_IN_SKY
+                    } else {
+                        Self::FRAME_STANDING
+                    };
+                Self::frame_enter(game_object, instance, frame_number)
+            }
+            FrameNumberNext::DELETE => {
+                instance.deleted = true;
+                Ok(())
+            }
+            next => {
+                if next.facing_switch() {
+                    instance.facing = instance.facing.reversed();
+                }
+                Self::frame_enter(game_object, instance, next.abs())
+            }
+        }
+    }
+
+    /// Switches an instance to a frame, applying the frame's velocity.
+    fn frame_enter(
+        game_object: &'d GameObject,
+        instance: &mut ObjectInstance,
+        frame_number: FrameNumber,
+    ) -> Result<(), SimError> {
+        let frame = Self::frame_find(game_object, frame_number)?;
+        instance.frame_number = frame_number;
+        instance.ticks_remaining = frame.wait.ticks();
+        instance.frame_entered = true;
+
+        let sign = instance.facing.sign();
+        let velocity = &mut instance.velocity;
+        velocity.x = Self::d_v_apply(velocity.x, frame.d_vx, sign);
+        velocity.y = Self::d_v_apply(velocity.y, frame.d_vy, 1.);
+        velocity.z = Self::d_v_apply(velocity.z, frame.d_vz, 1.);
+
+        if frame.state == State::DeleteObject {
+            instance.deleted = true;
+        }
+
+        Ok(())
+    }
+
+    fn d_v_apply(speed: f32, d_v: i64, sign: f32) -> f32 {
+        match d_v {
+            0 => speed,
+            Self::D_V_STOP => 0.,
+            d_v => d_v as f32 * sign,
+        }
+    }
+
+    /// Returns the first `cpoint` of the given kind in a frame.
+    fn c_point_find(frame: &Frame, kind: CPointKind) -> Option<&CPoint> {
+        frame.elements.iter().find_map(|element| match element {
+            Element::CPoint(c_point) if c_point.kind == kind => Some(c_point),
+            _ => None,
+        })
+    }
+
+    /// Returns whether both instances are live and not deleted.
+    fn instances_live(
+        instances: &[ObjectInstance],
+        instance_a: InstanceId,
+        instance_b: InstanceId,
+    ) -> bool {
+        [instance_a, instance_b].iter().all(|instance_id| {
+            instances
+                .binary_search_by_key(instance_id, |instance| instance.id)
+                .map(|index| !instances[index].deleted)
+                .unwrap_or(false)
+        })
+    }
+
+    fn grab_find(&self, instance_id: InstanceId) -> Option<usize> {
+        self.grabs
+            .iter()
+            .position(|grab| grab.catcher == instance_id || grab.caught == instance_id)
+    }
+
+    fn grab_action(
+        &mut self,
+        catcher: InstanceId,
+        action_fn: fn(&CPoint) -> FrameNumberNext,
+    ) -> Result<bool, SimError> {
+        let index = self
+            .instance_index(catcher)
+            .ok_or(SimError::InstanceNotFound {
+                instance_id: catcher,
+            })?;
+        let is_catcher = self.grabs.iter().any(|grab| grab.catcher == catcher);
+        if !is_catcher {
+            return Ok(false);
+        }
+
+        let game_object = self.game_object(self.instances[index].object_id)?;
+        let instance = &mut self.instances[index];
+        let frame = Self::frame_find(game_object, instance.frame_number)?;
+        let action = Self::c_point_find(frame, CPointKind::Catcher)
+            .map(action_fn)
+            .filter(|action| *action != FrameNumberNext::NONE);
+
+        match action {
+            Some(action) => {
+                Self::frame_transition(game_object, instance, frame, action)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Starts a `Grab` for each catching `itr` that overlaps a character.
+    fn grabs_begin(&mut self) -> Result<(), SimError> {
+        (0..self.instances.len()).try_for_each(|catcher_index| {
+            let catcher = &self.instances[catcher_index];
+            if catcher.deleted || self.grab_find(catcher.id).is_some() {
+                return Ok(());
+            }
+
+            let game_object = self.game_object(catcher.object_id)?;
+            let frame = Self::frame_find(game_object, catcher.frame_number)?;
+            let catch = frame
+                .elements
+                .iter()
+                .filter_map(|element| match element {
+                    Element::Itr(itr)
+                        if itr.kind == ItrKind::CatchStunned || itr.kind == ItrKind::CatchForce =>
+                    {
+                        Some(itr)
+                    }
+                    _ => None,
+                })
+                .find_map(|itr| {
+                    self.catch_target_find(catcher_index, frame, itr)
+                        .map(|caught_index| (itr, caught_index))
+                });
+
+            match catch {
+                Some((itr, caught_index)) => self.grab_start(catcher_index, caught_index, itr),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// Returns the index of a character that a catching `itr` overlaps.
+    fn catch_target_find(&self, catcher_index: usize, frame: &Frame, itr: &Itr) -> Option<usize> {
+        let catcher = &self.instances[catcher_index];
+        let itr_box = HitBox::from_itr(catcher.position, catcher.facing, frame, itr);
+
+        self.instances
+            .iter()
+            .enumerate()
+            .filter(|(index, target)| {
+                *index != catcher_index
+                    && !target.deleted
+                    && target.object_type == ObjectType::Character
+                    && self.grab_find(target.id).is_none()
+            })
+            .find(|(_, target)| {
+                let target_frame = match self.frame(target) {
+                    Some(target_frame) => target_frame,
+                    None => return false,
+                };
+                if itr.kind == ItrKind::CatchStunned && target_frame.state != State::Stunned {
+                    return false;
+                }
+
+                target_frame.elements.iter().any(|element| match element {
+                    Element::Bdy(bdy) => {
+                        let bdy_box =
+                            HitBox::from_bdy(target.position, target.facing, target_frame, bdy);
+                        itr_box.overlaps(&bdy_box)
+                    }
+                    _ => false,
+                })
+            })
+            .map(|(index, _)| index)
+    }
+
+    fn grab_start(
+        &mut self,
+        catcher_index: usize,
+        caught_index: usize,
+        itr: &Itr,
+    ) -> Result<(), SimError> {
+        let catcher_facing = self.instances[catcher_index].facing;
+        // The caught character's back is to the catcher when both face the
+        // same way.
+        let (catching_act, caught_act) = if self.instances[caught_index].facing == catcher_facing {
+            (itr.catching_act_back, itr.caught_act_back)
+        } else {
+            (itr.catching_act, itr.caught_act)
+        };
+        let catcher_game_object = self.game_object(self.instances[catcher_index].object_id)?;
+        let caught_game_object = self.game_object(self.instances[caught_index].object_id)?;
+
+        let catcher = &mut self.instances[catcher_index];
+        if catching_act.facing_switch() {
+            catcher.facing = catcher.facing.reversed();
+        }
+        Self::frame_enter(catcher_game_object, catcher, catching_act.abs())?;
+        let catcher_id = catcher.id;
+
+        // The caught character faces the catcher.
+        let caught = &mut self.instances[caught_index];
+        caught.facing = catcher_facing.reversed();
+        if caught_act.facing_switch() {
+            caught.facing = caught.facing.reversed();
+        }
+        Self::frame_enter(caught_game_object, caught, caught_act.abs())?;
+        caught.velocity = Velocity::default();
+
+        self.grabs.push(Grab {
+            catcher: catcher_id,
+            caught: caught.id,
+            timer: Self::GRAB_TIMER_INITIAL,
+            hurtable: true,
+            cover: false,
+        });
+
+        Ok(())
+    }
+
+    /// Moves held characters to the catcher's `cpoint`, applying injury,
+    /// throws, and releases.
+    fn grabs_update(&mut self) -> Result<(), SimError> {
+        let mut grabs = std::mem::take(&mut self.grabs);
+        let result = grabs
+            .iter_mut()
+            .try_fold(Vec::new(), |mut grabs_ended, grab| {
+                if !self.grab_update(grab)? {
+                    grabs_ended.push(grab.catcher);
+                }
+                Ok(grabs_ended)
+            });
+        grabs.retain(|grab| match &result {
+            Ok(grabs_ended) => !grabs_ended.contains(&grab.catcher),
+            Err(_) => true,
+        });
+        self.grabs = grabs;
+
+        result.map(|_| ())
+    }
+
+    /// Updates a held character, returning whether the grab continues.
+    fn grab_update(&mut self, grab: &mut Grab) -> Result<bool, SimError> {
+        let (catcher_index, caught_index) = match (
+            self.instance_index(grab.catcher),
+            self.instance_index(grab.caught),
+        ) {
+            (Some(catcher_index), Some(caught_index)) => (catcher_index, caught_index),
+            _ => return Ok(false),
+        };
+
+        let catcher = self.instances[catcher_index].clone();
+        let catcher_game_object = self.game_object(catcher.object_id)?;
+        let caught_game_object = self.game_object(self.instances[caught_index].object_id)?;
+        let catcher_frame = Self::frame_find(catcher_game_object, catcher.frame_number)?;
+        let caught = &mut self.instances[caught_index];
+
+        let c_point = match Self::c_point_find(catcher_frame, CPointKind::Catcher) {
+            Some(c_point) if !catcher.deleted && !caught.deleted => c_point,
+            _ => {
+                // Catcher left the catching frames.
+                if !caught.deleted {
+                    Self::frame_enter(caught_game_object, caught, Self::FRAME_JUMP)?;
+                }
+                return Ok(false);
+            }
+        };
+
+        grab.hurtable = c_point.hurtable;
+        grab.cover = c_point.cover;
+        if catcher.frame_entered && c_point.injury > 0 {
+            caught.hp -= c_point.injury;
+        }
+
+        if c_point.v_action != FrameNumber(0) && caught.frame_number != c_point.v_action {
+            Self::frame_enter(caught_game_object, caught, c_point.v_action)?;
+        }
+        Self::grab_align(&catcher, catcher_frame, c_point, caught, caught_game_object)?;
+
+        let throw_vx = Self::c_point_value(c_point.throw_vx);
+        let throw_vy = Self::c_point_value(c_point.throw_vy);
+        let throw_vz = Self::c_point_value(c_point.throw_vz);
+        let throw_injury = Self::c_point_value(c_point.throw_injury);
+        let is_throw = throw_vx != 0 || throw_vy != 0 || throw_vz != 0 || throw_injury != 0;
+        if catcher.frame_entered && is_throw {
+            caught.velocity = Velocity::new(
+                throw_vx as f32 * catcher.facing.sign(),
+                throw_vy as f32,
+                throw_vz as f32,
+            );
+            caught.throw_injury = throw_injury.max(0);
+            return Ok(false);
+        }
+
+        if c_point.decrease < 0 {
+            grab.timer += c_point.decrease;
+        }
+        if grab.timer <= 0 {
+            let frame_number = if c_point.decrease.abs() >= Self::GRAB_DECREASE_FALL {
+                Self::FRAME_FALLING
+            } else {
+                Self::FRAME_JUMP
+            };
+            Self::frame_enter(caught_game_object, caught, frame_number)?;
+
+            let catcher = &mut self.instances[catcher_index];
+            Self::frame_enter(catcher_game_object, catcher, Self::FRAME_STANDING)?;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Places the held character so that both `cpoint`s line up.
+    ///
+    /// The caught character is moved one unit along the Z axis, in front of
+    /// the catcher when the `cpoint` has `cover`, otherwise behind.
+    fn grab_align(
+        catcher: &ObjectInstance,
+        catcher_frame: &Frame,
+        c_point: &CPoint,
+        caught: &mut ObjectInstance,
+        caught_game_object: &'d GameObject,
+    ) -> Result<(), SimError> {
+        let caught_frame = Self::frame_find(caught_game_object, caught.frame_number)?;
+        let caught_point = Self::c_point_find(caught_frame, CPointKind::Caught)
+            .map(|caught_c_point| (caught_c_point.x, caught_c_point.y));
+
+        let mut anchor = Self::point_world(catcher, catcher_frame, c_point.x, c_point.y);
+        anchor.z += if c_point.cover { 1. } else { -1. };
+        Self::point_attach(caught, caught_frame, caught_point, anchor);
+
+        Ok(())
+    }
+
+    /// Returns the world position of a point in an instance's frame.
+    fn point_world(instance: &ObjectInstance, frame: &Frame, x: i32, y: i32) -> Position {
+        Position::new(
+            instance.position.x + (i64::from(x) - frame.center_x) as f32 * instance.facing.sign(),
+            instance.position.y + (i64::from(y) - frame.center_y) as f32,
+            instance.position.z,
+        )
+    }
+
+    /// Moves an instance so that a point in its frame is at `anchor`.
+    ///
+    /// Frames without the point have their center placed on the anchor.
+    fn point_attach(
+        instance: &mut ObjectInstance,
+        frame: &Frame,
+        point: Option<(i32, i32)>,
+        anchor: Position,
+    ) {
+        let (x, y) = point
+            .map(|(x, y)| (i64::from(x), i64::from(y)))
+            .unwrap_or((frame.center_x, frame.center_y));
+
+        instance.position = Position::new(
+            anchor.x - (x - frame.center_x) as f32 * instance.facing.sign(),
+            anchor.y - (y - frame.center_y) as f32,
+            anchor.z,
+        );
+        instance.velocity = Velocity::default();
+    }
+
+    /// Returns the first `wpoint` of the given kinds in a frame.
+    fn w_point_find<'f>(frame: &'f Frame, kinds: &[WPointKind]) -> Option<&'f WPoint> {
+        frame.elements.iter().find_map(|element| match element {
+            Element::WPoint(w_point) if kinds.contains(&w_point.kind) => Some(w_point),
+            _ => None,
+        })
+    }
+
+    /// Moves held weapons to the holder's `wpoint`, applying drops and
+    /// throws.
+    fn weapon_holds_update(&mut self) -> Result<(), SimError> {
+        let mut weapon_holds = std::mem::take(&mut self.weapon_holds);
+        let result =
+            weapon_holds
+                .iter_mut()
+                .try_fold(Vec::new(), |mut weapons_released, weapon_hold| {
+                    if !self.weapon_hold_update(weapon_hold)? {
+                        weapons_released.push(weapon_hold.weapon);
+                    }
+                    Ok(weapons_released)
+                });
+        weapon_holds.retain(|weapon_hold| match &result {
+            Ok(weapons_released) => !weapons_released.contains(&weapon_hold.weapon),
+            Err(_) => true,
+        });
+        self.weapon_holds = weapon_holds;
+
+        result.map(|_| ())
+    }
+
+    /// Updates a held weapon, returning whether it is still held.
+    fn weapon_hold_update(&mut self, weapon_hold: &mut WeaponHold) -> Result<bool, SimError> {
+        let (holder_index, weapon_index) = match (
+            self.instance_index(weapon_hold.holder),
+            self.instance_index(weapon_hold.weapon),
+        ) {
+            (Some(holder_index), Some(weapon_index)) => (holder_index, weapon_index),
+            _ => return Ok(false),
+        };
+
+        let holder = self.instances[holder_index].clone();
+        let holder_game_object = self.game_object(holder.object_id)?;
+        let weapon_game_object = self.game_object(self.instances[weapon_index].object_id)?;
+        let holder_frame = Self::frame_find(holder_game_object, holder.frame_number)?;
+        let weapon = &mut self.instances[weapon_index];
+        if weapon.deleted {
+            return Ok(false);
+        }
+
+        // Frames without a `wpoint` drop the weapon, like `kind: 3`.
+        let w_point =
+            Self::w_point_find(holder_frame, &[WPointKind::Holding, WPointKind::Dropping])
+                .filter(|w_point| w_point.kind == WPointKind::Holding && !holder.deleted);
+        let w_point = match w_point {
+            Some(w_point) => w_point,
+            None => {
+                Self::frame_enter(weapon_game_object, weapon, Self::FRAME_WEAPON_IN_SKY)?;
+                return Ok(false);
+            }
+        };
+
+        weapon_hold.attacking = w_point.attacking;
+        weapon.facing = if w_point.weapon_act.facing_switch() {
+            holder.facing.reversed()
+        } else {
+            holder.facing
+        };
+        let weapon_act = w_point.weapon_act.abs();
+        if w_point.weapon_act != FrameNumberNext::NONE && weapon.frame_number != weapon_act {
+            Self::frame_enter(weapon_game_object, weapon, weapon_act)?;
+        }
+
+        let weapon_frame = Self::frame_find(weapon_game_object, weapon.frame_number)?;
+        let weapon_point = Self::w_point_find(weapon_frame, &[WPointKind::Held])
+            .map(|weapon_w_point| (weapon_w_point.x, weapon_w_point.y));
+        let anchor = Self::point_world(&holder, holder_frame, w_point.x, w_point.y);
+        Self::point_attach(weapon, weapon_frame, weapon_point, anchor);
+
+        if holder.frame_entered && (w_point.d_vx != 0 || w_point.d_vy != 0 || w_point.d_vz != 0) {
+            let frame_number = if weapon.object_type == ObjectType::LightWeapon {
+                Self::FRAME_WEAPON_THROWING
+            } else {
+                Self::FRAME_WEAPON_IN_SKY
+            };
+            Self::frame_enter(weapon_game_object, weapon, frame_number)?;
+            weapon.velocity = Velocity::new(
+                w_point.d_vx as f32 * holder.facing.sign(),
+                w_point.d_vy as f32,
+                w_point.d_vz as f32,
+            );
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Returns `0` for `CPoint::VALUE_UNSET`, otherwise the value.
+    fn c_point_value(value: i32) -> i32 {
+        if value == CPoint::VALUE_UNSET {
+            0
+        } else {
+            value
+        }
+    }
+
+    fn o_points_spawn(&mut self, index: usize) -> Result<(), SimError> {
+        let instance = &mut self.instances[index];
+        if !instance.frame_entered || instance.deleted {
+            return Ok(());
+        }
+        instance.frame_entered = false;
+        let (object_id, frame_number) = (instance.object_id, instance.frame_number);
+
+        let game_object = self.game_object(object_id)?;
+        let frame = Self::frame_find(game_object, frame_number)?;
+        frame
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                Element::OPoint(o_point) => Some(o_point),
+                _ => None,
+            })
+            .try_for_each(|o_point| self.o_point_spawn(index, frame, o_point))
+    }
+
+    /// Spawns the objects described by an `opoint`.
+    ///
+    /// The spawned object's frame center is placed at the `opoint` coordinates
+    /// relative to the parent's frame. When `facing` requests more than one
+    /// copy, the copies are spread along the Z axis.
+    fn o_point_spawn(
+        &mut self,
+        parent_index: usize,
+        parent_frame: &Frame,
+        o_point: &OPoint,
+    ) -> Result<(), SimError> {
+        let parent = &self.instances[parent_index];
+        let parent_id = parent.id;
+        let parent_sign = parent.facing.sign();
+        let facing = match o_point.facing.direction {
+            OPointFacingDir::ParentSame => parent.facing,
+            OPointFacingDir::ParentOpposite => parent.facing.reversed(),
+            OPointFacingDir::Right => Facing::Right,
+        };
+        let position = Position::new(
+            parent.position.x + (o_point.x as f32 - parent_frame.center_x as f32) * parent_sign,
+            parent.position.y + (o_point.y as f32 - parent_frame.center_y as f32),
+            parent.position.z,
+        );
+
+        let count = o_point.facing.count.max(1);
+        (0..count).try_for_each(|copy_index| {
+            let instance = self.instantiate(
+                o_point.object_id,
+                o_point.action,
+                position,
+                facing,
+                Some(parent_id),
+            )?;
+
+            let velocity = &mut instance.velocity;
+            if o_point.d_vx != 0 {
+                velocity.x = o_point.d_vx as f32 * instance.facing.sign();
+            }
+            if o_point.d_vy != 0 {
+                velocity.y = o_point.d_vy as f32;
+            }
+            if count > 1 {
+                velocity.z =
+                    (copy_index as f32 - (count - 1) as f32 / 2.) * Self::O_POINT_SPREAD_VZ;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::Simulation;
+    use crate::{
+        sim::{Facing, InstanceId, ObjectInstance, Position, Velocity},
+        FrameNumber, GameData, GameObject, ObjectData, ObjectId, ObjectType,
+    };
+
+    const PARENT: ObjectId = ObjectId(1);
+    const CHILD: ObjectId = ObjectId(2);
+    const FIGHTER: ObjectId = ObjectId(3);
+    const HOLDER: ObjectId = ObjectId(4);
+    const WEAPON: ObjectId = ObjectId(5);
+    const CATCHER: InstanceId = InstanceId(0);
+    const CAUGHT: InstanceId = InstanceId(1);
+
+    /// Returns game data where the parent spawns the child through an
+    /// `opoint` with the given `facing` value.
+    fn game_data(o_point_facing: u32) -> GameData {
+        let parent = format!(
+            "<bmp_begin>\n<bmp_end>\n\
+             <frame> 0 spawn\n\
+             \x20 pic: 0  wait: 10  next: 0  centerx: 20  centery: 40\n\
+             \x20 opoint:\n\
+             \x20    kind: 1  x: 30  y: 30  action: 0  dvx: 5  dvy: 0  oid: 2  facing: {}\n\
+             \x20 opoint_end:\n\
+             <frame_end>\n",
+            o_point_facing
+        );
+        let child = "<bmp_begin>\n<bmp_end>\n\
+                     <frame> 0 flying\n  pic: 0  wait: 2  next: 1\n<frame_end>\n\
+                     <frame> 1 hit\n  pic: 0  wait: 0  next: 1\n<frame_end>\n";
+
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: PARENT,
+            object_type: ObjectType::Character,
+            object_data: ObjectData::try_from(parent.as_str()).unwrap(),
+        });
+        game_data.insert(GameObject {
+            id: CHILD,
+            object_type: ObjectType::Attack,
+            object_data: ObjectData::try_from(child).unwrap(),
+        });
+        game_data
+    }
+
+    fn children<'s>(simulation: &'s Simulation) -> Vec<&'s ObjectInstance> {
+        simulation
+            .instances()
+            .iter()
+            .filter(|instance| instance.object_id == CHILD)
+            .collect()
+    }
+
+    fn simulation_spawned(game_data: &GameData, facing: Facing) -> Simulation<'_> {
+        let mut simulation = Simulation::new(game_data);
+        simulation
+            .spawn(
+                PARENT,
+                FrameNumber(0),
+                Position::new(100., 0., 200.),
+                facing,
+            )
+            .unwrap();
+        simulation.tick().unwrap();
+        simulation
+    }
+
+    #[test]
+    fn o_point_spawns_relative_to_parent_center() {
+        let game_data = game_data(0);
+        let simulation = simulation_spawned(&game_data, Facing::Left);
+
+        let children = children(&simulation);
+        assert_eq!(1, children.len());
+        let child = children[0];
+        assert_eq!(Some(InstanceId(0)), child.parent);
+        assert_eq!(Position::new(90., -10., 200.), child.position);
+        assert_eq!(-5., child.velocity.x);
+    }
+
+    #[test]
+    fn o_point_facing_follows_parent() {
+        [
+            (0, Facing::Right, Facing::Right),
+            (0, Facing::Left, Facing::Left),
+            (1, Facing::Right, Facing::Left),
+            (1, Facing::Left, Facing::Right),
+            (10, Facing::Left, Facing::Right),
+        ]
+        .iter()
+        .for_each(|(o_point_facing, parent_facing, child_facing)| {
+            let game_data = game_data(*o_point_facing);
+            let simulation = simulation_spawned(&game_data, *parent_facing);
+
+            let children = children(&simulation);
+            assert_eq!(
+                vec![*child_facing],
+                children
+                    .iter()
+                    .map(|child| child.facing)
+                    .collect::<Vec<_>>(),
+                "facing: {}, parent facing {:?}",
+                o_point_facing,
+                parent_facing
+            );
+        });
+    }
+
+    #[test]
+    fn o_point_copies_spread_along_z() {
+        let game_data = game_data(31);
+        let simulation = simulation_spawned(&game_data, Facing::Right);
+
+        let children = children(&simulation);
+        assert!(children
+            .iter()
+            .all(|child| child.facing == Facing::Left && child.position.z == 200.));
+        assert_eq!(
+            vec![
+                -Simulation::O_POINT_SPREAD_VZ,
+                0.,
+                Simulation::O_POINT_SPREAD_VZ
+            ],
+            children
+                .iter()
+                .map(|child| child.velocity.z)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn o_point_child_stays_for_wait_plus_one_ticks() {
+        let game_data = game_data(0);
+        let mut simulation = simulation_spawned(&game_data, Facing::Right);
+
+        // `wait: 2` lasts 3 ticks, starting from the tick after the spawn.
+        (0..2).for_each(|_| {
+            simulation.tick().unwrap();
+            assert_eq!(FrameNumber(0), children(&simulation)[0].frame_number);
+        });
+        simulation.tick().unwrap();
+        assert_eq!(FrameNumber(1), children(&simulation)[0].frame_number);
+    }
+
+    /// Returns game data with a character that catches on frame 1, holds on
+    /// frame 120 and moves on to frame 121.
+    ///
+    /// # Parameters
+    ///
+    /// * `c_point_hold`: Extra `cpoint` tags for frame 120.
+    /// * `c_point_next`: Extra `cpoint` tags for frame 121.
+    fn game_data_fighter(c_point_hold: &str, c_point_next: &str) -> GameData {
+        let frame = |number: u32, name: &str, wait: u32, next: u32, elements: &str| {
+            format!(
+                "<frame> {} {}\n\
+                 \x20 pic: 0  wait: {}  next: {}  centerx: 40  centery: 80\n\
+                 {}\
+                 <frame_end>\n",
+                number, name, wait, next, elements
+            )
+        };
+        let bdy = "  bdy:\n     kind: 0  x: 0  y: 0  w: 80  h: 80\n  bdy_end:\n";
+        let catch = "  itr:\n     kind: 3  x: 40  y: 0  w: 80  h: 80  \
+                     catchingact: 120 120  caughtact: 130 131\n  itr_end:\n";
+        let c_point = |tags: &str| {
+            format!(
+                "  cpoint:\n     kind: 1  x: 50  y: 40  {}\n  cpoint_end:\n",
+                tags
+            )
+        };
+        let caught = "  cpoint:\n     kind: 2  x: 30  y: 50\n  cpoint_end:\n";
+
+        let fighter = [
+            "<bmp_begin>\n<bmp_end>\n".to_string(),
+            frame(0, "standing", 10, 0, bdy),
+            frame(1, "catch", 1, 0, &format!("{}{}", catch, bdy)),
+            frame(120, "catching", 1, 121, &c_point(c_point_hold)),
+            frame(121, "catching_next", 10, 0, &c_point(c_point_next)),
+            frame(130, "caught", 10, 0, caught),
+            frame(131, "caught_back", 10, 0, caught),
+            frame(181, "falling", 10, 0, ""),
+            frame(211, "jump", 10, 0, ""),
+        ]
+        .concat();
+
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: FIGHTER,
+            object_type: ObjectType::Character,
+            object_data: ObjectData::try_from(fighter.as_str()).unwrap(),
+        });
+        game_data
+    }
+
+    /// Spawns a catcher facing right, and a character in front of it facing
+    /// the catcher, then runs the tick in which the grab begins.
+    fn simulation_grabbed(game_data: &GameData) -> Simulation<'_> {
+        simulation_grabbed_facing(game_data, Facing::Left)
+    }
+
+    fn simulation_grabbed_facing(game_data: &GameData, caught_facing: Facing) -> Simulation<'_> {
+        let mut simulation = Simulation::new(game_data);
+        simulation
+            .spawn(
+                FIGHTER,
+                FrameNumber(1),
+                Position::new(100., 0., 200.),
+                Facing::Right,
+            )
+            .unwrap();
+        simulation
+            .spawn(
+                FIGHTER,
+                FrameNumber(0),
+                Position::new(150., 0., 200.),
+                caught_facing,
+            )
+            .unwrap();
+        simulation.tick().unwrap();
+        simulation
+    }
+
+    #[test]
+    fn grab_aligns_caught_c_point_with_catcher_c_point() {
+        [(0, 199.), (1, 201.)]
+            .iter()
+            .for_each(|(cover, z_expected)| {
+                let game_data = game_data_fighter(&format!("cover: {}", cover), "");
+                let simulation = simulation_grabbed(&game_data);
+
+                assert_eq!(1, simulation.grabs().len());
+                assert_eq!(*cover == 1, simulation.grabs()[0].cover);
+                let caught = simulation.instance(CAUGHT).unwrap();
+                assert_eq!(FrameNumber(130), caught.frame_number);
+                assert_eq!(Facing::Left, caught.facing);
+                // Catcher `cpoint` is at (110, -40), the caught character's
+                // `cpoint` is mirrored as it faces left.
+                assert_eq!(Position::new(100., -10., *z_expected), caught.position);
+            });
+    }
+
+    #[test]
+    fn grab_from_behind_uses_back_acts() {
+        let game_data = game_data_fighter("", "");
+        [
+            (Facing::Left, FrameNumber(130)),
+            (Facing::Right, FrameNumber(131)),
+        ]
+        .iter()
+        .for_each(|(caught_facing, frame_expected)| {
+            let simulation = simulation_grabbed_facing(&game_data, *caught_facing);
+
+            let caught = simulation.instance(CAUGHT).unwrap();
+            assert_eq!(*frame_expected, caught.frame_number);
+            assert_eq!(Facing::Left, caught.facing);
+        });
+    }
+
+    #[test]
+    fn grab_hurtable_follows_c_point() {
+        [(0, false), (1, true)]
+            .iter()
+            .for_each(|(hurtable, hurtable_expected)| {
+                let game_data = game_data_fighter(&format!("hurtable: {}", hurtable), "");
+                let simulation = simulation_grabbed(&game_data);
+
+                assert_eq!(*hurtable_expected, simulation.hurtable(CAUGHT));
+                assert!(simulation.hurtable(CATCHER));
+            });
+    }
+
+    #[test]
+    fn grab_timer_expiry_releases_caught_character() {
+        [
+            (-150, 2, Simulation::FRAME_FALLING),
+            (-6, 50, Simulation::FRAME_JUMP),
+        ]
+        .iter()
+        .for_each(|(decrease, grab_ticks, frame_expected)| {
+            let game_data = game_data_fighter(
+                &format!("decrease: {}", decrease),
+                &format!("decrease: {}", decrease),
+            );
+            let mut simulation = simulation_grabbed(&game_data);
+            (1..*grab_ticks).for_each(|_| {
+                assert_eq!(1, simulation.grabs().len());
+                simulation.tick().unwrap();
+            });
+
+            assert!(simulation.grabs().is_empty());
+            let caught = simulation.instance(CAUGHT).unwrap();
+            assert_eq!(*frame_expected, caught.frame_number);
+            let catcher = simulation.instance(CATCHER).unwrap();
+            assert_eq!(Simulation::FRAME_STANDING, catcher.frame_number);
+        });
+    }
+
+    #[test]
+    fn grab_throws_on_any_throw_value() {
+        [
+            ("throwvx: 10", Velocity::new(10., 0., 0.), 0),
+            ("throwvy: -8", Velocity::new(0., -8., 0.), 0),
+            ("throwvz: 3", Velocity::new(0., 0., 3.), 0),
+            ("throwinjury: 20", Velocity::new(0., 0., 0.), 20),
+        ]
+        .iter()
+        .for_each(
+            |(c_point_throw, velocity_expected, throw_injury_expected)| {
+                let game_data = game_data_fighter("throwinjury: -842150451", c_point_throw);
+                let mut simulation = simulation_grabbed(&game_data);
+                simulation.tick().unwrap();
+                assert_eq!(1, simulation.grabs().len(), "{}", c_point_throw);
+
+                // The catcher enters the throwing frame on this tick.
+                simulation.tick().unwrap();
+                assert!(simulation.grabs().is_empty(), "{}", c_point_throw);
+                let caught = simulation.instance(CAUGHT).unwrap();
+                assert_eq!(*velocity_expected, caught.velocity, "{}", c_point_throw);
+                assert_eq!(*throw_injury_expected, caught.throw_injury);
+            },
+        );
+    }
+
+    /// Returns game data with a character that holds a weapon on frame 0, and
+    /// throws it on frame 1.
+    fn game_data_weapon(weapon_type: ObjectType) -> GameData {
+        let holder = "<bmp_begin>\n<bmp_end>\n\
+             <frame> 0 holding\n  pic: 0  wait: 1  next: 1\n\
+             \x20 wpoint:\n     kind: 1  x: 40  y: 50  weaponact: 20\n  wpoint_end:\n\
+             <frame_end>\n\
+             <frame> 1 throwing\n  pic: 0  wait: 10  next: 0\n\
+             \x20 wpoint:\n     kind: 1  x: 40  y: 50  weaponact: 20  dvx: 12  dvy: -3  dvz: 2\n\
+             \x20 wpoint_end:\n\
+             <frame_end>\n";
+        let weapon = "<bmp_begin>\n<bmp_end>\n\
+             <frame> 0 in_the_sky\n  pic: 0  wait: 10  next: 0\n<frame_end>\n\
+             <frame> 20 held\n  pic: 0  wait: 10  next: 0\n<frame_end>\n\
+             <frame> 40 throwing\n  pic: 0  wait: 10  next: 0\n<frame_end>\n";
+
+        let mut game_data = GameData::new();
+        game_data.insert(GameObject {
+            id: HOLDER,
+            object_type: ObjectType::Character,
+            object_data: ObjectData::try_from(holder).unwrap(),
+        });
+        game_data.insert(GameObject {
+            id: WEAPON,
+            object_type: weapon_type,
+            object_data: ObjectData::try_from(weapon).unwrap(),
+        });
+        game_data
+    }
+
+    #[test]
+    fn weapon_throw_enters_throwing_frames_with_w_point_velocity() {
+        [
+            (ObjectType::LightWeapon, Simulation::FRAME_WEAPON_THROWING),
+            (ObjectType::HeavyWeapon, Simulation::FRAME_WEAPON_IN_SKY),
+        ]
+        .iter()
+        .for_each(|(weapon_type, frame_expected)| {
+            let game_data = game_data_weapon(*weapon_type);
+            let mut simulation = Simulation::new(&game_data);
+            let position = Position::new(100., 0., 200.);
+            let holder = simulation
+                .spawn(HOLDER, FrameNumber(0), position, Facing::Left)
+                .unwrap();
+            let weapon = simulation
+                .spawn(WEAPON, FrameNumber(0), position, Facing::Right)
+                .unwrap();
+            assert!(simulation.weapon_hold(holder, weapon).unwrap());
+            assert_eq!(
+                FrameNumber(20),
+                simulation.instance(weapon).unwrap().frame_number
+            );
+
+            simulation.tick().unwrap();
+            assert_eq!(1, simulation.weapon_holds().len());
+
+            // The holder enters the throwing frame on this tick.
+            simulation.tick().unwrap();
+            assert!(simulation.weapon_holds().is_empty());
+            let weapon = simulation.instance(weapon).unwrap();
+            assert_eq!(*frame_expected, weapon.frame_number, "{:?}", weapon_type);
+            assert_eq!(Velocity::new(-12., -3., 2.), weapon.velocity);
+        });
+    }
+}
@@ -0,0 +1,17 @@
+/// Velocity of an object in pixels per tick.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Velocity {
+    /// Horizontal velocity, positive values move to the right.
+    pub x: f32,
+    /// Vertical velocity, positive values move downwards.
+    pub y: f32,
+    /// Depth velocity, positive values move towards the screen.
+    pub z: f32,
+}
+
+impl Velocity {
+    /// Returns a new `Velocity`.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{sim::InstanceId, WeaponStrengthIndex};
+
+/// A weapon that is held by a character through `wpoint`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeaponHold {
+    /// Instance that is holding the weapon.
+    pub holder: InstanceId,
+    /// Instance of the weapon.
+    pub weapon: InstanceId,
+    /// Attack strength selected by the holder's `wpoint`.
+    ///
+    /// `0` when the holder is not attacking with the weapon.
+    pub attacking: WeaponStrengthIndex,
+}
@@ -0,0 +1,14 @@
+//! Decoding and slicing of sprite sheet images.
+
+pub(crate) use self::bmp::Bmp;
+
+pub use self::{
+    sprite_error::SpriteError, sprite_image::SpriteImage, sprite_sheet::SpriteSheet,
+    sprites::Sprites,
+};
+
+mod bmp;
+mod sprite_error;
+mod sprite_image;
+mod sprite_sheet;
+mod sprites;
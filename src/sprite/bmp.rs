@@ -0,0 +1,190 @@
+use std::path::Path;
+
+use crate::{SpriteError, SpriteImage};
+
+/// Size of the `BITMAPFILEHEADER`.
+const FILE_HEADER_LEN: usize = 14;
+/// Size of the smallest supported DIB header, `BITMAPINFOHEADER`.
+const INFO_HEADER_LEN: usize = 40;
+/// Uncompressed pixel data.
+const BI_RGB: u32 = 0;
+/// Uncompressed pixel data with channel masks, accepted for 32 bit images.
+const BI_BITFIELDS: u32 = 3;
+
+/// Decodes uncompressed Windows bitmaps.
+///
+/// Black pixels are decoded as transparent, as that is how the game draws
+/// sprites.
+#[derive(Debug)]
+pub(crate) struct Bmp;
+
+impl Bmp {
+    /// Decodes the bytes of a bitmap file.
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: Path to the file, used in error messages.
+    /// * `bytes`: Contents of the file.
+    pub(crate) fn decode(path: &Path, bytes: &[u8]) -> Result<SpriteImage, SpriteError> {
+        let invalid = |reason| SpriteError::BmpInvalid {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        if bytes.len() < FILE_HEADER_LEN + INFO_HEADER_LEN || &bytes[0..2] != b"BM" {
+            return Err(invalid("missing `BM` file header"));
+        }
+        let pixel_offset = Self::u32_at(bytes, 10) as usize;
+        let info_header_len = Self::u32_at(bytes, 14) as usize;
+        if info_header_len < INFO_HEADER_LEN {
+            return Err(invalid("DIB header is too short"));
+        }
+        let width = Self::u32_at(bytes, 18) as i32;
+        let height = Self::u32_at(bytes, 22) as i32;
+        let bit_depth = Self::u16_at(bytes, 28);
+        let compression = Self::u32_at(bytes, 30);
+        let palette_len = Self::u32_at(bytes, 46) as usize;
+
+        if width <= 0 || height == 0 {
+            return Err(invalid("image has no pixels"));
+        }
+        match (bit_depth, compression) {
+            (8, BI_RGB) | (24, BI_RGB) | (32, BI_RGB) | (32, BI_BITFIELDS) => {}
+            (8, _) | (24, _) | (32, _) => return Err(invalid("image is compressed")),
+            _ => {
+                return Err(SpriteError::BmpBitDepthUnsupported {
+                    path: path.to_path_buf(),
+                    bit_depth,
+                })
+            }
+        }
+
+        let width = width as u32;
+        // Positive heights are stored bottom row first.
+        let bottom_up = height > 0;
+        let height = height.unsigned_abs();
+        // Sizes are checked, as the header may claim dimensions that overflow.
+        let stride = u32::from(bit_depth)
+            .checked_mul(width)
+            .map(|row_bits| row_bits.div_ceil(32) as usize * 4)
+            .ok_or_else(|| invalid("image is too wide"))?;
+        let pixels_end = stride
+            .checked_mul(height as usize)
+            .and_then(|pixels_len| pixels_len.checked_add(pixel_offset))
+            .ok_or_else(|| invalid("image is too large"))?;
+        if bytes.len() < pixels_end {
+            return Err(invalid("pixel data is truncated"));
+        }
+        (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixel_count| pixel_count.checked_mul(4))
+            .ok_or_else(|| invalid("image is too large"))?;
+
+        let palette = if bit_depth == 8 {
+            let palette_start = FILE_HEADER_LEN + info_header_len;
+            let palette_len = if palette_len == 0 { 256 } else { palette_len };
+            let palette_end = palette_len
+                .checked_mul(4)
+                .and_then(|palette_size| palette_size.checked_add(palette_start))
+                .ok_or_else(|| invalid("palette is too large"))?;
+            if bytes.len() < palette_end {
+                return Err(invalid("palette is truncated"));
+            }
+            bytes[palette_start..palette_end]
+                .chunks_exact(4)
+                .map(|bgra| [bgra[2], bgra[1], bgra[0]])
+                .collect::<Vec<[u8; 3]>>()
+        } else {
+            Vec::new()
+        };
+
+        let mut image = SpriteImage::new(width, height);
+        (0..height).for_each(|y| {
+            let row = if bottom_up { height - 1 - y } else { y };
+            let row_start = pixel_offset + stride * row as usize;
+            (0..width).for_each(|x| {
+                let rgb = match bit_depth {
+                    8 => palette
+                        .get(usize::from(bytes[row_start + x as usize]))
+                        .copied()
+                        .unwrap_or_default(),
+                    _ => {
+                        let offset = row_start + x as usize * usize::from(bit_depth / 8);
+                        [bytes[offset + 2], bytes[offset + 1], bytes[offset]]
+                    }
+                };
+                let alpha = if rgb == [0, 0, 0] { 0 } else { 255 };
+                image.pixel_set(x, y, [rgb[0], rgb[1], rgb[2], alpha]);
+            })
+        });
+
+        Ok(image)
+    }
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Bmp;
+    use crate::SpriteError;
+
+    /// Returns a 24 bit bitmap with the given dimensions and pixel data.
+    fn bmp(width: i32, height: i32, pixels: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"BM");
+        bytes.extend_from_slice(&(54 + pixels.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&54u32.to_le_bytes());
+        bytes.extend_from_slice(&40u32.to_le_bytes());
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&24u16.to_le_bytes());
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(pixels);
+        bytes
+    }
+
+    #[test]
+    fn decodes_bottom_up_rows_with_black_transparent() {
+        // Rows are padded to 4 bytes, bottom row first, in BGR order.
+        let pixels = [
+            0, 0, 255, 0, 0, 0, 0, 0, /* top */ 255, 0, 0, 0, 255, 0, 0, 0,
+        ];
+        let image = Bmp::decode(Path::new("test.bmp"), &bmp(2, 2, &pixels)).unwrap();
+
+        assert_eq!(Some([0, 0, 255, 255]), image.pixel(0, 0));
+        assert_eq!(Some([0, 255, 0, 255]), image.pixel(1, 0));
+        assert_eq!(Some([255, 0, 0, 255]), image.pixel(0, 1));
+        assert_eq!(Some([0, 0, 0, 0]), image.pixel(1, 1));
+    }
+
+    #[test]
+    fn rejects_dimensions_that_overflow() {
+        [(i32::MAX, 1), (0x2000_0000, 0x2000_0000), (1, i32::MIN)]
+            .iter()
+            .for_each(|&(width, height)| {
+                let result = Bmp::decode(Path::new("test.bmp"), &bmp(width, height, &[0; 16]));
+                assert!(
+                    matches!(result, Err(SpriteError::BmpInvalid { .. })),
+                    "{}x{}",
+                    width,
+                    height
+                );
+            });
+    }
+}
@@ -0,0 +1,61 @@
+use std::{fmt, fmt::Display, io, path::PathBuf};
+
+/// Errors when loading sprite sheets.
+#[derive(Debug)]
+pub enum SpriteError {
+    /// Failed to read an image file.
+    FileReadError {
+        /// Path to the image file.
+        path: PathBuf,
+        /// The `io::Error` returned by the OS.
+        io_error: io::Error,
+    },
+    /// An image file is not a bitmap that can be decoded.
+    BmpInvalid {
+        /// Path to the image file.
+        path: PathBuf,
+        /// Why the file could not be decoded.
+        reason: &'static str,
+    },
+    /// A bitmap uses a bit depth that is not supported.
+    BmpBitDepthUnsupported {
+        /// Path to the image file.
+        path: PathBuf,
+        /// Bits per pixel of the image.
+        bit_depth: u16,
+    },
+}
+
+impl Display for SpriteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FileReadError { path, io_error } => write!(
+                f,
+                "Failed to read image file: `{}`. Error: `{}`.",
+                path.display(),
+                io_error
+            ),
+            Self::BmpInvalid { path, reason } => write!(
+                f,
+                "Failed to decode `{}` as a bitmap: {}.",
+                path.display(),
+                reason
+            ),
+            Self::BmpBitDepthUnsupported { path, bit_depth } => write!(
+                f,
+                "`{}` uses {} bits per pixel. Only 8, 24, and 32 bit bitmaps are supported.",
+                path.display(),
+                bit_depth
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SpriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::FileReadError { io_error, .. } => Some(io_error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/// RGBA image of a sprite.
+///
+/// Pixels are stored row by row from the top left, 4 bytes per pixel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpriteImage {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// RGBA bytes, `width * height * 4` long.
+    pub pixels: Vec<u8>,
+}
+
+impl SpriteImage {
+    /// Returns a fully transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Returns the RGBA value of a pixel, if it is within the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            let mut rgba = [0; 4];
+            rgba.copy_from_slice(&self.pixels[index..index + 4]);
+            Some(rgba)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the RGBA value of a pixel, ignoring pixels outside the image.
+    pub fn pixel_set(&mut self, x: u32, y: u32, rgba: [u8; 4]) {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            self.pixels[index..index + 4].copy_from_slice(&rgba);
+        }
+    }
+
+    /// Returns the part of this image within the given rectangle.
+    ///
+    /// Pixels outside this image are transparent.
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> SpriteImage {
+        let mut image = SpriteImage::new(width, height);
+        (0..height).for_each(|y_offset| {
+            (0..width).for_each(|x_offset| {
+                if let Some(rgba) = self.pixel(x + x_offset, y + y_offset) {
+                    image.pixel_set(x_offset, y_offset, rgba);
+                }
+            })
+        });
+        image
+    }
+
+    /// Returns this image mirrored left to right.
+    pub fn flipped(&self) -> SpriteImage {
+        let mut image = SpriteImage::new(self.width, self.height);
+        (0..self.height).for_each(|y| {
+            (0..self.width).for_each(|x| {
+                if let Some(rgba) = self.pixel(x, y) {
+                    image.pixel_set(self.width - 1 - x, y, rgba);
+                }
+            })
+        });
+        image
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y as usize * self.width as usize + x as usize) * 4
+    }
+}
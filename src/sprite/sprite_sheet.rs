@@ -0,0 +1,35 @@
+use crate::{SpriteFile, SpriteImage};
+
+/// Decoded sprite sheet image with its cell layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteSheet {
+    /// Layout of the cells, from the object data header.
+    pub sprite_file: SpriteFile,
+    /// Decoded sheet image.
+    pub image: SpriteImage,
+}
+
+impl SpriteSheet {
+    /// Returns whether the given pic number is in this sheet.
+    pub fn contains(&self, pic: usize) -> bool {
+        self.sprite_file.pic_range().contains(&pic)
+    }
+
+    /// Returns the image for the given pic number, if it is in this sheet.
+    ///
+    /// Cells are laid out left to right, then top to bottom, each followed by a
+    /// 1 pixel gutter.
+    pub fn pic(&self, pic: usize) -> Option<SpriteImage> {
+        if !self.contains(pic) {
+            return None;
+        }
+        let cell = pic - self.sprite_file.pic_range().start();
+        let row = self.sprite_file.row().max(1) as usize;
+        let w = self.sprite_file.w();
+        let h = self.sprite_file.h();
+        let x = (cell % row) as u32 * (w + 1);
+        let y = (cell / row) as u32 * (h + 1);
+
+        Some(self.image.crop(x, y, w, h))
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{sprite::Bmp, Frame, Header, Pic, SpriteError, SpriteImage, SpriteSheet};
+
+/// Sprite sheets of an object.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sprites {
+    /// Sheets in the order they are declared in the object header.
+    pub sheets: Vec<SpriteSheet>,
+}
+
+impl Sprites {
+    /// Loads the sprite sheets declared in an object header.
+    ///
+    /// # Parameters
+    ///
+    /// * `game_dir`: Directory that the sprite file paths are relative to.
+    /// * `header`: Header of the object data.
+    pub fn load(game_dir: &Path, header: &Header) -> Result<Self, SpriteError> {
+        let sheets = header
+            .sprite_files
+            .iter()
+            .map(|sprite_file| {
+                let path = Self::path_resolve(game_dir, sprite_file.path());
+                let bytes = fs::read(&path).map_err(|io_error| SpriteError::FileReadError {
+                    path: path.clone(),
+                    io_error,
+                })?;
+                let image = Bmp::decode(&path, &bytes)?;
+
+                Ok(SpriteSheet {
+                    sprite_file: sprite_file.clone(),
+                    image,
+                })
+            })
+            .collect::<Result<Vec<_>, SpriteError>>()?;
+
+        Ok(Self { sheets })
+    }
+
+    /// Returns the image for a pic, mirrored when the pic number is negative.
+    pub fn pic(&self, pic: Pic) -> Option<SpriteImage> {
+        self.sheets
+            .iter()
+            .find_map(|sheet| sheet.pic(pic.abs()))
+            .map(|image| {
+                if pic.facing_switch() {
+                    image.flipped()
+                } else {
+                    image
+                }
+            })
+    }
+
+    /// Returns the image for a frame's pic.
+    pub fn frame_image(&self, frame: &Frame) -> Option<SpriteImage> {
+        self.pic(frame.pic)
+    }
+
+    /// Returns the path to a file referenced by object data.
+    ///
+    /// Data files separate path segments with `\`, which is converted to the
+    /// platform's separator.
+    pub fn path_resolve(game_dir: &Path, path: &Path) -> PathBuf {
+        path.to_string_lossy()
+            .split(['\\', '/'])
+            .filter(|segment| !segment.is_empty())
+            .fold(game_dir.to_path_buf(), |path, segment| path.join(segment))
+    }
+}
@@ -1,12 +1,22 @@
-use std::{convert::TryFrom, path::PathBuf};
+use std::{
+    convert::TryFrom,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
 
 use pest::iterators::Pair;
 
 use crate::{Error, ObjectDataParser, Rule, SubRuleFn};
 
+/// Sprite sheet image and the layout of its cells.
+///
+/// Cells are `w` by `h` pixels, with a 1 pixel gutter to the right and below
+/// each cell.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct SpriteFile {
     path: PathBuf,
+    pic_first: usize,
+    pic_last: Option<usize>,
     w: u32,
     h: u32,
     row: u32,
@@ -14,6 +24,79 @@ pub struct SpriteFile {
 }
 
 impl SpriteFile {
+    /// Returns the path to the image, relative to the game directory.
+    ///
+    /// This uses `\` as the separator, as written in the data file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the width of each cell.
+    pub fn w(&self) -> u32 {
+        self.w
+    }
+
+    /// Returns the height of each cell.
+    pub fn h(&self) -> u32 {
+        self.h
+    }
+
+    /// Returns the number of cells in each row.
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    /// Returns the number of rows of cells.
+    pub fn col(&self) -> u32 {
+        self.col
+    }
+
+    /// Returns the number of cells in the sheet, `row * col`.
+    pub fn pic_count(&self) -> usize {
+        self.row as usize * self.col as usize
+    }
+
+    /// Returns the pic numbers in this sheet, from the `file(a-b)` tag.
+    ///
+    /// When the range end is not specified, it is computed from the number of
+    /// cells.
+    pub fn pic_range(&self) -> RangeInclusive<usize> {
+        let pic_last = self
+            .pic_last
+            .unwrap_or_else(|| (self.pic_first + self.pic_count()).saturating_sub(1));
+        self.pic_first..=pic_last
+    }
+
+    fn parse_key<'i>(
+        sprite_file: SpriteFile,
+        key_pair: Pair<'i, Rule>,
+    ) -> Result<SpriteFile, Error<'i>> {
+        key_pair
+            .into_inner()
+            .try_fold(sprite_file, Self::parse_key_value)
+    }
+
+    fn parse_key_value<'i>(
+        mut sprite_file: SpriteFile,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<SpriteFile, Error<'i>> {
+        let rule = value_pair.as_rule();
+        let pic = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(file),
+                value_pair,
+                error,
+            })?;
+        match rule {
+            Rule::SpriteFilePicFirst => sprite_file.pic_first = pic,
+            Rule::SpriteFilePicLast => sprite_file.pic_last = Some(pic),
+            _ => {}
+        }
+        Ok(sprite_file)
+    }
+
     fn parse_path<'i>(
         sprite_file: SpriteFile,
         path_pair: Pair<'i, Rule>,
@@ -160,6 +243,7 @@ impl<'i> TryFrom<Pair<'i, Rule>> for SpriteFile {
             pair,
             Rule::SpriteFile,
             &[
+                Self::parse_key,
                 Self::parse_path,
                 Self::parse_w,
                 Self::parse_h,
@@ -1,10 +1,18 @@
+use std::convert::TryFrom;
+
+use pest::iterators::Pair;
+
+use crate::{Effect, Error, ObjectDataParser, Rule, SubRuleFn, WeaponStrengthIndex};
+
 /// Attack strength of a light weapon.
 ///
 /// This is used when the `attacking` tag on a `WPoint` `kind: 1` is non-zero.
 ///
 /// See https://lf-empire.de/lf2-empire/data-changing/types/168-type-1-light-weapons
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct WeaponStrength {
+    /// Index that `WPoint`s refer to through `attacking`.
+    pub entry: WeaponStrengthIndex,
     /// Acceleration to place on the hit object in the X axis.
     pub d_vx: i64,
     /// Acceleration to place on the hit object in the Y axis.
@@ -80,4 +88,246 @@ pub struct WeaponStrength {
     pub b_defend: i32,
     /// Amount of damage to inflict on the target object.
     pub injury: i32,
+    /// Itr `effect` variants.
+    pub effect: Effect,
+}
+
+impl WeaponStrength {
+    fn parse_entry<'i>(
+        weapon_strength: WeaponStrength,
+        entry_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        entry_pair
+            .into_inner()
+            .try_fold(weapon_strength, Self::parse_entry_value)
+    }
+
+    fn parse_entry_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        // The entry name is kept in `WeaponStrengthList::names`.
+        if value_pair.as_rule() == Rule::TagEntryValue {
+            let entry = value_pair
+                .as_str()
+                .parse()
+                .map_err(|error| Error::ParseInt {
+                    field: stringify!(entry),
+                    value_pair,
+                    error,
+                })?;
+            weapon_strength.entry = entry;
+        }
+        Ok(weapon_strength)
+    }
+
+    fn parse_tags<'i>(
+        weapon_strength: WeaponStrength,
+        weapon_strength_data_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        weapon_strength_data_pair
+            .into_inner()
+            .try_fold(weapon_strength, WeaponStrength::parse_tag)
+    }
+
+    fn parse_tag<'i>(
+        weapon_strength: WeaponStrength,
+        weapon_strength_tag_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        ObjectDataParser::parse_as_type(
+            weapon_strength,
+            weapon_strength_tag_pair,
+            Rule::WeaponStrengthTag,
+            &[Self::parse_tag_value as SubRuleFn<_>],
+        )
+    }
+
+    fn parse_tag_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        weapon_strength_tag_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        weapon_strength = match weapon_strength_tag_pair.as_rule() {
+            Rule::TagDVx => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_d_vx_value,
+            )?,
+            Rule::TagDVy => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_d_vy_value,
+            )?,
+            Rule::TagARest => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_arest_value,
+            )?,
+            Rule::TagVRest => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_vrest_value,
+            )?,
+            Rule::TagFall => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_fall_value,
+            )?,
+            Rule::TagBDefend => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_b_defend_value,
+            )?,
+            Rule::TagInjury => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_injury_value,
+            )?,
+            Rule::TagEffect => ObjectDataParser::parse_value(
+                weapon_strength,
+                weapon_strength_tag_pair,
+                Self::parse_effect_value,
+            )?,
+            _ => weapon_strength,
+        };
+        Ok(weapon_strength)
+    }
+
+    fn parse_d_vx_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let d_vx = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(d_vx),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.d_vx = d_vx;
+        Ok(weapon_strength)
+    }
+
+    fn parse_d_vy_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let d_vy = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(d_vy),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.d_vy = d_vy;
+        Ok(weapon_strength)
+    }
+
+    fn parse_arest_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let arest = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(arest),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.arest = arest;
+        Ok(weapon_strength)
+    }
+
+    fn parse_vrest_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let vrest = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(vrest),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.vrest = vrest;
+        Ok(weapon_strength)
+    }
+
+    fn parse_fall_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let fall = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(fall),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.fall = fall;
+        Ok(weapon_strength)
+    }
+
+    fn parse_b_defend_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let b_defend = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(b_defend),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.b_defend = b_defend;
+        Ok(weapon_strength)
+    }
+
+    fn parse_injury_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let injury = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseInt {
+                field: stringify!(injury),
+                value_pair,
+                error,
+            })?;
+        weapon_strength.injury = injury;
+        Ok(weapon_strength)
+    }
+
+    fn parse_effect_value<'i>(
+        mut weapon_strength: WeaponStrength,
+        value_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrength, Error<'i>> {
+        let effect = value_pair
+            .as_str()
+            .parse()
+            .map_err(|error| Error::ParseItrEffect { value_pair, error })?;
+        weapon_strength.effect = effect;
+        Ok(weapon_strength)
+    }
+}
+
+impl<'i> TryFrom<Pair<'i, Rule>> for WeaponStrength {
+    type Error = Error<'i>;
+
+    fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
+        let sub_rule_fns: &[SubRuleFn<_>] =
+            &[WeaponStrength::parse_entry, WeaponStrength::parse_tags];
+        ObjectDataParser::parse_as_type(
+            WeaponStrength::default(),
+            pair,
+            Rule::WeaponStrength,
+            sub_rule_fns,
+        )
+    }
 }
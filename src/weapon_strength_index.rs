@@ -6,7 +6,7 @@ use std::{
 };
 
 /// Represents the index in the [`WeaponStrengthList`].
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct WeaponStrengthIndex(pub usize);
 
 impl Deref for WeaponStrengthIndex {
@@ -0,0 +1,207 @@
+use std::{collections::BTreeMap, convert::TryFrom, ops::Deref};
+
+use pest::iterators::Pair;
+
+use crate::{Error, Rule, WeaponStrength, WeaponStrengthIndex};
+
+/// Attack strengths of a light weapon, selected by `WPoint` `attacking`.
+///
+/// This is the `<weapon_strength_list>` section of a weapon data file.
+///
+/// Entries are modified through [`WeaponStrengthList::insert`] and
+/// [`WeaponStrengthList::remove`], so that each name stays with its entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WeaponStrengthList {
+    /// Entries in file order.
+    entries: Vec<WeaponStrength>,
+    /// Names of the entries, e.g. `normal`, `jump`, `run`, `dash`.
+    ///
+    /// Names are stored here so that [`WeaponStrength`] stays `Copy`.
+    names: BTreeMap<WeaponStrengthIndex, String>,
+}
+
+impl WeaponStrengthList {
+    /// Returns the entry with the given index, if any.
+    ///
+    /// Index `0` means the weapon is not attacking, and never has an entry.
+    pub fn entry(&self, index: WeaponStrengthIndex) -> Option<&WeaponStrength> {
+        self.entries
+            .iter()
+            .find(|weapon_strength| weapon_strength.entry == index && *index != 0)
+    }
+
+    /// Returns the name of the entry with the given index, if any.
+    pub fn name(&self, index: WeaponStrengthIndex) -> Option<&str> {
+        self.names.get(&index).map(String::as_str)
+    }
+
+    /// Inserts an entry, returning the entry it replaces.
+    ///
+    /// An entry that replaces another with the same index takes its position,
+    /// otherwise it is added after the last entry.
+    ///
+    /// # Parameters
+    ///
+    /// * `weapon_strength`: Entry to insert.
+    /// * `name`: Name of the entry, e.g. `normal`.
+    pub fn insert(
+        &mut self,
+        weapon_strength: WeaponStrength,
+        name: Option<String>,
+    ) -> Option<WeaponStrength> {
+        let index = weapon_strength.entry;
+        match name {
+            Some(name) => self.names.insert(index, name),
+            None => self.names.remove(&index),
+        };
+
+        match self
+            .entries
+            .iter_mut()
+            .find(|weapon_strength_existing| weapon_strength_existing.entry == index)
+        {
+            Some(weapon_strength_existing) => {
+                Some(std::mem::replace(weapon_strength_existing, weapon_strength))
+            }
+            None => {
+                self.entries.push(weapon_strength);
+                None
+            }
+        }
+    }
+
+    /// Removes and returns the entry with the given index, along with its
+    /// name.
+    ///
+    /// # Parameters
+    ///
+    /// * `index`: Index of the entry to remove.
+    pub fn remove(&mut self, index: WeaponStrengthIndex) -> Option<WeaponStrength> {
+        self.names.remove(&index);
+        let position = self
+            .entries
+            .iter()
+            .position(|weapon_strength| weapon_strength.entry == index)?;
+        Some(self.entries.remove(position))
+    }
+
+    fn parse_weapon_strength<'i>(
+        mut weapon_strength_list: WeaponStrengthList,
+        weapon_strength_pair: Pair<'i, Rule>,
+    ) -> Result<WeaponStrengthList, Error<'i>> {
+        let name = weapon_strength_pair
+            .clone()
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::TagEntry)
+            .flat_map(Pair::into_inner)
+            .find(|pair| pair.as_rule() == Rule::TagEntryName)
+            .map(|name_pair| name_pair.as_str().to_string());
+        let weapon_strength = WeaponStrength::try_from(weapon_strength_pair)?;
+        weapon_strength_list.insert(weapon_strength, name);
+        Ok(weapon_strength_list)
+    }
+}
+
+impl Deref for WeaponStrengthList {
+    type Target = [WeaponStrength];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl<'i> TryFrom<Pair<'i, Rule>> for WeaponStrengthList {
+    type Error = Error<'i>;
+
+    fn try_from(pair: Pair<'i, Rule>) -> Result<Self, Self::Error> {
+        if pair.as_rule() == Rule::WeaponStrengthList {
+            pair.into_inner().try_fold(
+                WeaponStrengthList::default(),
+                WeaponStrengthList::parse_weapon_strength,
+            )
+        } else {
+            Err(Error::GrammarSingle {
+                rule_expected: Rule::WeaponStrengthList,
+                pair_found: Some(pair),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::WeaponStrengthList;
+    use crate::{ObjectData, WeaponStrength, WeaponStrengthIndex};
+
+    #[test]
+    fn keeps_entry_names_by_index() {
+        let object_data_str = "<bmp_begin>\n<bmp_end>\n\
+            <weapon_strength_list>\n\
+            entry: 1 normal\n  dvx: 8  fall: 40  injury: 20\n\
+            entry: 2 jump\n  dvx: 10  injury: 30\n\
+            <weapon_strength_list_end>\n\
+            <frame> 0 in_the_sky\n  pic: 0  wait: 1  next: 0\n<frame_end>\n";
+        let object_data = ObjectData::try_from(object_data_str).unwrap();
+        let weapon_strength_list = &object_data.weapon_strength_list;
+
+        let jump = *weapon_strength_list.entry(WeaponStrengthIndex(2)).unwrap();
+        assert_eq!(10, jump.d_vx);
+        assert_eq!(30, jump.injury);
+        assert_eq!(
+            Some("normal"),
+            weapon_strength_list.name(WeaponStrengthIndex(1))
+        );
+        assert_eq!(
+            Some("jump"),
+            weapon_strength_list.name(WeaponStrengthIndex(2))
+        );
+    }
+
+    #[test]
+    fn insert_and_remove_keep_names_with_entries() {
+        let mut weapon_strength_list = WeaponStrengthList::default();
+        let normal = WeaponStrength {
+            entry: WeaponStrengthIndex(1),
+            injury: 20,
+            ..Default::default()
+        };
+        let jump = WeaponStrength {
+            entry: WeaponStrengthIndex(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            None,
+            weapon_strength_list.insert(normal, Some(String::from("normal")))
+        );
+        assert_eq!(
+            None,
+            weapon_strength_list.insert(jump, Some(String::from("jump")))
+        );
+
+        let normal_strong = WeaponStrength {
+            injury: 40,
+            ..normal
+        };
+        assert_eq!(
+            Some(normal),
+            weapon_strength_list.insert(normal_strong, None)
+        );
+        assert_eq!(
+            vec![WeaponStrengthIndex(1), WeaponStrengthIndex(2)],
+            weapon_strength_list
+                .iter()
+                .map(|weapon_strength| weapon_strength.entry)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, weapon_strength_list.name(WeaponStrengthIndex(1)));
+
+        assert_eq!(
+            Some(jump),
+            weapon_strength_list.remove(WeaponStrengthIndex(2))
+        );
+        assert_eq!(None, weapon_strength_list.name(WeaponStrengthIndex(2)));
+        assert_eq!(1, weapon_strength_list.len());
+    }
+}